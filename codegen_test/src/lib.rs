@@ -6,9 +6,10 @@ macro_rules! generate {
             )*
         }
         $(
+            $(#[target_cpu = $cpu:literal])?
             $id:literal: fn $name:ident($(
                 $arg:ident: $argty:ty
-            ),* $(,)?) -> $ret:ty $blk:block
+            ),* $(,)?) $(-> $ret:ty)? $blk:block
         )*
     ) => {
         use index_many::*;
@@ -20,12 +21,13 @@ macro_rules! generate {
         $(
             pub unsafe fn $name($(
                 $arg: $argty
-            ),*) -> $ret $blk
+            ),*) $(-> $ret)? $blk
         )*
 
         pub struct Function {
             pub id: i32,
             pub name: &'static str,
+            pub target_cpu: Option<&'static str>,
             pub full_item_source: &'static str,
             pub body_source: &'static str,
         }
@@ -40,10 +42,16 @@ macro_rules! generate {
                 Function {
                     id: $id,
                     name: stringify!($name),
+                    target_cpu: {
+                        #[allow(unused_variables)]
+                        let cpu: Option<&'static str> = None;
+                        $(let cpu = Some($cpu);)?
+                        cpu
+                    },
                     full_item_source: stringify!(
                         pub unsafe fn $name($(
                             $arg: $argty
-                        ),*) -> $ret $blk
+                        ),*) $(-> $ret)? $blk
                     ),
                     body_source: stringify!($blk),
                 },
@@ -54,6 +62,9 @@ macro_rules! generate {
 
 generate! {
     header {
+        #[allow(unused_imports)]
+        use std::cell::Cell;
+
         #[allow(unused_imports)]
         use std::ops::Range;
 
@@ -332,4 +343,102 @@ generate! {
     ) -> Option<[&mut Elem; LEN]> {
         std_proposal::SliceExt::get_many_mut_res_indirect_niche(slice, indices).ok()
     }
+
+    7: fn option_cells(
+        slice: &mut [Elem],
+        indices: [usize; LEN],
+    ) -> Option<[&Cell<Elem>; LEN]> {
+        cell::get_many_cells(slice, indices)
+    }
+    7: fn checked_cells(
+        slice: &mut [Elem],
+        indices: [usize; LEN],
+    ) -> [&Cell<Elem>; LEN] {
+        cell::index_many_cells(slice, indices)
+    }
+    7: fn checked_cell_ranges(
+        slice: &mut [Elem],
+        indices: [Range<usize>; LEN],
+    ) -> [&[Cell<Elem>]; LEN] {
+        cell::index_many_cell_ranges(slice, indices)
+    }
+
+    8: fn checked_indices_for(
+        slice: &mut [Elem],
+        indices: generic::IndicesFor<generic::UnsortedIndices<LEN>>,
+    ) -> [&mut Elem; LEN] {
+        generic::index_many_mut(slice, indices)
+    }
+
+    9: fn option_stencil(
+        stencil: stencil::Stencil<LEN>,
+        slice: &mut [Elem],
+        center: usize,
+    ) -> Option<[&mut Elem; LEN]> {
+        stencil.get_many_mut(slice, center)
+    }
+    9: fn checked_stencil(
+        stencil: stencil::Stencil<LEN>,
+        slice: &mut [Elem],
+        center: usize,
+    ) -> [&mut Elem; LEN] {
+        stencil.index_many_mut(slice, center)
+    }
+    9: fn option_stencil_2d(
+        stencil: stencil::Stencil2d<5>,
+        slice: &mut [Elem],
+        x: usize,
+        y: usize,
+    ) -> Option<[&mut Elem; 5]> {
+        stencil.get_many_mut(slice, x, y)
+    }
+
+    10: fn option_strided(
+        slice: &mut [Elem],
+        indices: generic::Strided<LEN>,
+    ) -> Option<[&mut Elem; LEN]> {
+        generic::get_many_mut(slice, indices)
+    }
+    10: fn checked_strided(
+        slice: &mut [Elem],
+        indices: generic::Strided<LEN>,
+    ) -> [&mut Elem; LEN] {
+        generic::index_many_mut(slice, indices)
+    }
+
+    11: fn option_const_range_trait(
+        slice: &mut [Elem],
+        indices: [slice_index::ConstRange<4>; LEN],
+    ) -> Option<[&mut [Elem; 4]; LEN]> {
+        slice_index::get_many_mut(slice, indices)
+    }
+    11: fn checked_const_range_trait(
+        slice: &mut [Elem],
+        indices: [slice_index::ConstRange<4>; LEN],
+    ) -> [&mut [Elem; 4]; LEN] {
+        slice_index::index_many_mut(slice, indices)
+    }
+
+    #[target_cpu = "x86-64-v3"]
+    12: fn option_gather(
+        slice: &[Elem],
+        indices: [usize; 4],
+    ) -> Option<[Elem; 4]> {
+        gather::try_gather(slice, indices)
+    }
+    #[target_cpu = "x86-64-v3"]
+    12: fn checked_gather(
+        slice: &[Elem],
+        indices: [usize; 4],
+    ) -> [Elem; 4] {
+        gather::gather(slice, indices)
+    }
+    #[target_cpu = "x86-64-v4"]
+    12: fn checked_scatter(
+        slice: &mut [Elem],
+        indices: generic::UnsortedIndices<4>,
+        values: [Elem; 4],
+    ) {
+        gather::scatter(slice, indices, values)
+    }
 }
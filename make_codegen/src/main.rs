@@ -5,8 +5,13 @@ use std::{collections::HashMap, path::Path, process::Output};
 use tempfile::tempdir;
 
 fn run_raw(s: &str, cwd: &Path, pipe: bool) -> Output {
+    run_raw_with_env(s, cwd, pipe, &[])
+}
+
+fn run_raw_with_env(s: &str, cwd: &Path, pipe: bool, env: &[(&str, String)]) -> Output {
     let args = s.split_whitespace().collect::<Vec<_>>();
     let mut cmd = std::process::Command::new(&args[0]);
+    cmd.envs(env.iter().map(|(k, v)| (k, v)));
     if pipe {
         cmd.stderr(std::process::Stdio::piped());
         cmd.stdout(std::process::Stdio::piped());
@@ -94,6 +99,7 @@ fn main() {
         let codegen_test::Function {
             id,
             name,
+            target_cpu,
             full_item_source: source,
             body_source: body,
         } = *function;
@@ -107,7 +113,16 @@ fn main() {
             r"cargo asm {}::{} --no-color {}",
             codegen_crate_name, name, asm_style
         );
-        let out = run_raw(&s, &tempdir, true);
+        // Functions that need newer instructions are built with their own
+        // target cpu, on top of any flags from the environment.
+        let env = match target_cpu {
+            Some(cpu) => {
+                let flags = std::env::var("RUSTFLAGS").unwrap_or_default();
+                vec![("RUSTFLAGS", format!("{} -C target-cpu={}", flags, cpu))]
+            }
+            None => Vec::new(),
+        };
+        let out = run_raw_with_env(&s, &tempdir, true, &env);
 
         let asm = match out.status.exit_ok() {
             Ok(_) => {
@@ -120,13 +135,16 @@ fn main() {
                     r##"
                     /// Body: `{}`
                     ///
-                    /// # Assembly (x86_64)
+                    /// # Assembly (x86_64{})
                     /// ```x86asm
                     {}
                     /// ```
                     {}
                     "##,
                     body.lines().map(|l| l.trim()).collect::<Vec<_>>().join(" "),
+                    target_cpu
+                        .map(|cpu| format!(", `-C target-cpu={}`", cpu))
+                        .unwrap_or_default(),
                     asm.lines()
                         .map(|l| format!("/// {}\n", l))
                         .collect::<String>()
@@ -6,6 +6,8 @@ use crate::*;
 #[allow(unused_imports)]
 use simple_result::{GetManyError, GetManyErrorKind};
 #[allow(unused_imports)]
+use std::cell::Cell;
+#[allow(unused_imports)]
 use std::ops::Range;
 #[allow(unused_imports)]
 use std_proposal::{Error, ErrorKind, ErrorNiche, ErrorSimple};
@@ -17,24 +19,24 @@ pub const LEN: usize = 3;
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::option_simple:
-///  mov     r10, qword, ptr, [r9, +, 16]
-///  cmp     r10, r8
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r8, rdx
 ///  jae     .LBB0_4
-///  mov     r8, qword, ptr, [r9]
-///  mov     rcx, qword, ptr, [r9, +, 8]
-///  cmp     r8, rcx
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rcx, qword, ptr, [rcx, +, 8]
+///  cmp     rdx, rcx
 ///  jae     .LBB0_4
-///  cmp     rcx, r10
+///  cmp     rcx, r8
 ///  jae     .LBB0_4
-///  lea     r8, [rdx, +, 8*r8]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     rdx, [rdx, +, 8*r10]
-///  mov     qword, ptr, [rax], r8
+///  lea     rdi, [rsi, +, 8*rdx]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rdx, [rsi, +, 8*r8]
 ///  mov     qword, ptr, [rax, +, 8], rcx
 ///  mov     qword, ptr, [rax, +, 16], rdx
-///  ret
 /// .LBB0_4:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn option_simple(slice: &mut [Elem], indices: [usize; LEN]) -> Option<[&mut Elem; LEN]> {
@@ -46,24 +48,24 @@ pub unsafe fn option_simple(slice: &mut [Elem], indices: [usize; LEN]) -> Option
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::option_generic:
-///  mov     r10, qword, ptr, [r9, +, 16]
-///  cmp     r10, r8
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r8, rdx
 ///  jae     .LBB0_4
-///  mov     r8, qword, ptr, [r9]
-///  mov     rcx, qword, ptr, [r9, +, 8]
-///  cmp     r8, rcx
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rcx, qword, ptr, [rcx, +, 8]
+///  cmp     rdx, rcx
 ///  jae     .LBB0_4
-///  cmp     rcx, r10
+///  cmp     rcx, r8
 ///  jae     .LBB0_4
-///  lea     r8, [rdx, +, 8*r8]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     rdx, [rdx, +, 8*r10]
-///  mov     qword, ptr, [rax], r8
+///  lea     rdi, [rsi, +, 8*rdx]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rdx, [rsi, +, 8*r8]
 ///  mov     qword, ptr, [rax, +, 8], rcx
 ///  mov     qword, ptr, [rax, +, 16], rdx
-///  ret
 /// .LBB0_4:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn option_generic(
@@ -78,24 +80,24 @@ pub unsafe fn option_generic(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::option_usize_trait:
-///  mov     r10, qword, ptr, [r9, +, 16]
-///  cmp     r10, r8
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r8, rdx
 ///  jae     .LBB0_4
-///  mov     r8, qword, ptr, [r9]
-///  mov     rcx, qword, ptr, [r9, +, 8]
-///  cmp     r8, rcx
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rcx, qword, ptr, [rcx, +, 8]
+///  cmp     rdx, rcx
 ///  jae     .LBB0_4
-///  cmp     rcx, r10
+///  cmp     rcx, r8
 ///  jae     .LBB0_4
-///  lea     r8, [rdx, +, 8*r8]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     rdx, [rdx, +, 8*r10]
-///  mov     qword, ptr, [rax], r8
+///  lea     rdi, [rsi, +, 8*rdx]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rdx, [rsi, +, 8*r8]
 ///  mov     qword, ptr, [rax, +, 8], rcx
 ///  mov     qword, ptr, [rax, +, 16], rdx
-///  ret
 /// .LBB0_4:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn option_usize_trait(
@@ -110,46 +112,44 @@ pub unsafe fn option_usize_trait(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::option_range_trait:
-///  push    rsi
-///  push    rdi
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx]
+///  mov     r11, qword, ptr, [rcx, +, 8]
+///  xor     edi, edi
+///  mov     r9, r11
+///  sub     r9, r8
+///  jb      .LBB0_8
+///  mov     r10, qword, ptr, [rcx, +, 16]
+///  cmp     r11, r10
+///  ja      .LBB0_8
+///  push    r14
 ///  push    rbx
-///  mov     rax, rcx
-///  mov     rcx, qword, ptr, [r9, +, 40]
-///  cmp     rcx, r8
-///  ja      .LBB0_6
-///  mov     r10, qword, ptr, [r9]
-///  mov     rsi, qword, ptr, [r9, +, 8]
-///  mov     r8, rsi
-///  sub     r8, r10
-///  jb      .LBB0_6
-///  mov     r11, qword, ptr, [r9, +, 16]
-///  cmp     rsi, r11
-///  ja      .LBB0_6
-///  mov     rbx, qword, ptr, [r9, +, 24]
-///  mov     rdi, rbx
-///  sub     rdi, r11
-///  jb      .LBB0_6
-///  mov     rsi, qword, ptr, [r9, +, 32]
-///  cmp     rbx, rsi
-///  ja      .LBB0_6
-///  sub     rcx, rsi
-///  jb      .LBB0_6
-///  lea     rbx, [rdx, +, 8*r10]
-///  lea     r9, [rdx, +, 8*r11]
-///  lea     rdx, [rdx, +, 8*rsi]
-///  mov     qword, ptr, [rax], rbx
-///  mov     qword, ptr, [rax, +, 8], r8
-///  mov     qword, ptr, [rax, +, 16], r9
-///  mov     qword, ptr, [rax, +, 24], rdi
-///  mov     qword, ptr, [rax, +, 32], rdx
+///  mov     r14, qword, ptr, [rcx, +, 24]
+///  mov     r11, r14
+///  sub     r11, r10
+///  jb      .LBB0_7
+///  mov     rbx, qword, ptr, [rcx, +, 32]
+///  cmp     r14, rbx
+///  ja      .LBB0_7
+///  mov     r14, qword, ptr, [rcx, +, 40]
+///  mov     rcx, r14
+///  sub     rcx, rbx
+///  jb      .LBB0_7
+///  cmp     r14, rdx
+///  ja      .LBB0_7
+///  lea     rdi, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r10]
+///  lea     rsi, [rsi, +, 8*rbx]
+///  mov     qword, ptr, [rax, +, 8], r9
+///  mov     qword, ptr, [rax, +, 16], rdx
+///  mov     qword, ptr, [rax, +, 24], r11
+///  mov     qword, ptr, [rax, +, 32], rsi
 ///  mov     qword, ptr, [rax, +, 40], rcx
-///  jmp     .LBB0_8
-/// .LBB0_6:
-///  mov     qword, ptr, [rax], 0
-/// .LBB0_8:
+/// .LBB0_7:
 ///  pop     rbx
-///  pop     rdi
-///  pop     rsi
+///  pop     r14
+/// .LBB0_8:
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn option_range_trait(
@@ -164,30 +164,30 @@ pub unsafe fn option_range_trait(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::option_unsorted:
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rcx, qword, ptr, [r9, +, 16]
-///  cmp     rcx, r10
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r9, r8
 ///  je      .LBB0_7
-///  mov     r9, qword, ptr, [r9]
-///  cmp     rcx, r9
+///  mov     rcx, qword, ptr, [rcx]
+///  cmp     r9, rcx
 ///  je      .LBB0_7
-///  cmp     rcx, r8
+///  cmp     r9, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r9
+///  cmp     r8, rcx
 ///  je      .LBB0_7
-///  cmp     r9, r8
+///  cmp     rcx, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r8
+///  cmp     r8, rdx
 ///  jae     .LBB0_7
-///  lea     r8, [rdx, +, 8*r9]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  mov     qword, ptr, [rax], r8
-///  mov     qword, ptr, [rax, +, 8], r9
-///  mov     qword, ptr, [rax, +, 16], rcx
-///  ret
+///  lea     rdi, [rsi, +, 8*rcx]
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     qword, ptr, [rax, +, 16], rdx
 /// .LBB0_7:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn option_unsorted(
@@ -202,29 +202,31 @@ pub unsafe fn option_unsorted(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::result_simple:
-///  mov     r11, qword, ptr, [r9]
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rcx, qword, ptr, [r9, +, 16]
-///  cmp     rcx, r8
-///  jae     .LBB0_4
-///  cmp     r11, r10
-///  jae     .LBB0_4
-///  cmp     r10, rcx
-///  jae     .LBB0_4
-///  lea     r8, [rdx, +, 8*r11]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  mov     qword, ptr, [rax, +, 8], r8
-///  mov     qword, ptr, [rax, +, 16], r9
-///  mov     qword, ptr, [rax, +, 24], rcx
+///  mov     rax, rdi
+///  mov     rdi, qword, ptr, [rcx, +, 16]
+///  cmp     rdi, rdx
+///  jae     .LBB0_3
+///  mov     r8, qword, ptr, [rcx]
+///  mov     r9, qword, ptr, [rcx, +, 8]
+///  cmp     r8, r9
+///  jae     .LBB0_3
+///  cmp     r9, rdi
+///  jae     .LBB0_3
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  lea     rsi, [rsi, +, 8*rdi]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     qword, ptr, [rax, +, 16], rdx
+///  mov     qword, ptr, [rax, +, 24], rsi
 ///  xor     ecx, ecx
 ///  mov     qword, ptr, [rax], rcx
 ///  ret
-/// .LBB0_4:
-///  mov     qword, ptr, [rax, +, 8], r11
-///  mov     qword, ptr, [rax, +, 16], r10
-///  mov     qword, ptr, [rax, +, 24], rcx
-///  mov     qword, ptr, [rax, +, 32], r8
+/// .LBB0_3:
+///  mov     rsi, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rax, +, 24], rsi
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movups  xmmword, ptr, [rax, +, 8], xmm0
+///  mov     qword, ptr, [rax, +, 32], rdx
 ///  mov     ecx, 1
 ///  mov     qword, ptr, [rax], rcx
 ///  ret
@@ -241,78 +243,89 @@ pub unsafe fn result_simple(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::result_kind:
-///  sub     rsp, 40
-///  mov     rax, rcx
-///  mov     rcx, qword, ptr, [r9]
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  cmp     r9, r8
-///  jae     .LBB0_4
-///  cmp     rcx, r10
-///  jae     .LBB0_4
-///  cmp     r10, r9
-///  jae     .LBB0_4
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rax, +, 8], rcx
-///  mov     qword, ptr, [rax, +, 16], r8
-///  mov     qword, ptr, [rax, +, 24], rdx
-///  xor     ecx, ecx
-///  mov     qword, ptr, [rax], rcx
-///  add     rsp, 40
+///  sub     rsp, 24
+///  mov     rax, rdi
+///  mov     rdi, qword, ptr, [rcx]
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     rcx, qword, ptr, [rcx, +, 16]
+///  cmp     rcx, rdx
+///  jae     .LBB0_3
+///  cmp     rdi, r8
+///  jae     .LBB0_3
+///  cmp     r8, rcx
+///  jae     .LBB0_3
+///  lea     rdx, [rsi, +, 8*rdi]
+///  lea     rdi, [rsi, +, 8*r8]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  mov     qword, ptr, [rax, +, 8], rdx
+///  mov     qword, ptr, [rax, +, 16], rdi
+///  mov     qword, ptr, [rax, +, 24], rcx
+///  mov     qword, ptr, [rax], 3
+///  add     rsp, 24
 ///  ret
-/// .LBB0_4:
-///  cmp     rcx, r10
-///  jne     .LBB0_9
-///  xor     edx, edx
-/// .LBB0_6:
-///  lea     r10, [rdx, +, 1]
+/// .LBB0_3:
+///  cmp     rdi, r8
+///  jne     .LBB0_11
+///  xor     r9d, r9d
+/// .LBB0_5:
+///  lea     rdx, [r9, +, 1]
 ///  mov     ecx, 2
-///  jmp     .LBB0_12
-/// .LBB0_9:
-///  cmp     rcx, r10
-///  jbe     .LBB0_17
-///  xor     edx, edx
+///  jmp     .LBB0_16
 /// .LBB0_11:
-///  lea     r10, [rdx, +, 1]
-///  mov     ecx, 1
-/// .LBB0_12:
-///  mov     qword, ptr, [rax, +, 8], rcx
-///  mov     qword, ptr, [rax, +, 16], rdx
-///  mov     qword, ptr, [rax, +, 24], r10
-///  mov     qword, ptr, [rax, +, 32], r8
+///  jbe     .LBB0_13
+///  xor     r9d, r9d
+/// .LBB0_15:
+///  lea     rdx, [r9, +, 1]
 ///  mov     ecx, 1
+/// .LBB0_16:
+///  mov     rsi, rsp
+///  lea     r8, [rsp, +, 8]
+///  mov     rdi, r9
+/// .LBB0_17:
+///  mov     qword, ptr, [r8], rdi
+///  mov     qword, ptr, [rsi], rdx
 ///  mov     qword, ptr, [rax], rcx
-///  add     rsp, 40
+///  mov     rcx, qword, ptr, [rsp, +, 8]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     rcx, qword, ptr, [rsp]
+///  mov     qword, ptr, [rax, +, 16], rcx
+///  mov     rcx, qword, ptr, [rsp, +, 16]
+///  mov     qword, ptr, [rax, +, 24], rcx
+///  add     rsp, 24
 ///  ret
-/// .LBB0_17:
-///  mov     edx, 1
-///  cmp     r10, r9
-///  je      .LBB0_6
-///  mov     edx, 1
-///  ja      .LBB0_11
-///  cmp     rcx, r8
-///  jae     .LBB0_8
-///  cmp     r10, r8
-///  mov     edx, 2
-///  adc     rdx, -1
-///  xor     ecx, ecx
-///  cmp     r10, r8
-///  cmovb   r10, r9
-///  jae     .LBB0_12
-///  cmp     r9, r8
-///  jae     .LBB0_12
-///  lea     rcx, [rip, +, __unnamed_1]
-///  lea     r8, [rip, +, __unnamed_2]
-///  mov     edx, 40
-///  call    core::panicking::panic
-///  ud2
-/// .LBB0_8:
-///  xor     edx, edx
-///  mov     r10, rcx
+/// .LBB0_13:
+///  mov     r9d, 1
+///  cmp     r8, rcx
+///  je      .LBB0_5
+///  ja      .LBB0_15
+///  cmp     rdx, rdi
+///  jbe     .LBB0_7
+///  cmp     rcx, rdx
+///  setae   r9b
+///  xor     esi, esi
+///  cmp     rdx, r8
+///  setbe   r10b
+///  seta    dil
+///  cmova   r8, rcx
+///  or      r10b, r9b
+///  je      .LBB0_20
+///  mov     sil, dil
+///  inc     rsi
+///  mov     rdi, r8
+///  jmp     .LBB0_10
+/// .LBB0_7:
+///  xor     esi, esi
+/// .LBB0_10:
+///  mov     qword, ptr, [rsp, +, 8], rsi
+///  lea     rsi, [rsp, +, 16]
+///  mov     r8, rsp
 ///  xor     ecx, ecx
-///  jmp     .LBB0_12
+///  jmp     .LBB0_17
+/// .LBB0_20:
+///  lea     rdi, [rip, +, .Lanon.5eb00334086f5bda3be966074ba2ca48.0]
+///  lea     rdx, [rip, +, .Lanon.5eb00334086f5bda3be966074ba2ca48.2]
+///  mov     esi, 40
+///  call    qword, ptr, [rip, +, _RNvNtCsgEmfK2I1SDS_4core9panicking5panic@GOTPCREL]
 /// ```
 pub unsafe fn result_kind(
     slice: &mut [Elem],
@@ -326,24 +339,24 @@ pub unsafe fn result_kind(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::result_option:
-///  mov     r10, qword, ptr, [r9, +, 16]
-///  cmp     r10, r8
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r8, rdx
 ///  jae     .LBB0_4
-///  mov     r8, qword, ptr, [r9]
-///  mov     rcx, qword, ptr, [r9, +, 8]
-///  cmp     r8, rcx
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rcx, qword, ptr, [rcx, +, 8]
+///  cmp     rdx, rcx
 ///  jae     .LBB0_4
-///  cmp     rcx, r10
+///  cmp     rcx, r8
 ///  jae     .LBB0_4
-///  lea     r8, [rdx, +, 8*r8]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     rdx, [rdx, +, 8*r10]
-///  mov     qword, ptr, [rax], r8
+///  lea     rdi, [rsi, +, 8*rdx]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rdx, [rsi, +, 8*r8]
 ///  mov     qword, ptr, [rax, +, 8], rcx
 ///  mov     qword, ptr, [rax, +, 16], rdx
-///  ret
 /// .LBB0_4:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn result_option(slice: &mut [Elem], indices: [usize; LEN]) -> Option<[&mut Elem; LEN]> {
@@ -355,33 +368,32 @@ pub unsafe fn result_option(slice: &mut [Elem], indices: [usize; LEN]) -> Option
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_simple:
-///  sub     rsp, 56
-///  mov     r10, qword, ptr, [r9]
-///  mov     rax, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  cmp     r9, r8
-///  jae     .LBB0_3
-///  cmp     r10, rax
-///  jae     .LBB0_3
-///  cmp     rax, r9
-///  jae     .LBB0_3
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rcx], r8
-///  mov     qword, ptr, [rcx, +, 8], rax
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 56
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  cmp     rax, rdx
+///  jae     .LBB0_4
+///  mov     r8, qword, ptr, [rcx]
+///  mov     r9, qword, ptr, [rcx, +, 8]
+///  cmp     r8, r9
+///  jae     .LBB0_4
+///  cmp     r9, rax
+///  jae     .LBB0_4
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  lea     rax, [rsi, +, 8*rax]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rdx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_3:
-///  mov     qword, ptr, [rsp, +, 32], r10
-///  mov     qword, ptr, [rsp, +, 40], rax
-///  mov     qword, ptr, [rsp, +, 48], r9
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 3
-///  call    index_many::sorted_bound_check_failed
-///  ud2
+/// .LBB0_4:
+///  sub     rsp, 24
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rsp, +, 16], rax
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 3
+///  call    qword, ptr, [rip, +, _ZN10index_many25sorted_bound_check_failed17hde68f0a1bd1fc49dE@GOTPCREL]
 /// ```
 pub unsafe fn checked_simple(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
     simple::index_many_mut(slice, indices)
@@ -392,33 +404,32 @@ pub unsafe fn checked_simple(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_generic:
-///  sub     rsp, 56
-///  mov     r10, qword, ptr, [r9]
-///  mov     rax, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  cmp     r9, r8
-///  jae     .LBB0_3
-///  cmp     r10, rax
-///  jae     .LBB0_3
-///  cmp     rax, r9
-///  jae     .LBB0_3
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rcx], r8
-///  mov     qword, ptr, [rcx, +, 8], rax
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 56
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  cmp     rax, rdx
+///  jae     .LBB0_4
+///  mov     r8, qword, ptr, [rcx]
+///  mov     r9, qword, ptr, [rcx, +, 8]
+///  cmp     r8, r9
+///  jae     .LBB0_4
+///  cmp     r9, rax
+///  jae     .LBB0_4
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  lea     rax, [rsi, +, 8*rax]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rdx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_3:
-///  mov     qword, ptr, [rsp, +, 32], r10
-///  mov     qword, ptr, [rsp, +, 40], rax
-///  mov     qword, ptr, [rsp, +, 48], r9
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 3
-///  call    index_many::sorted_bound_check_failed
-///  ud2
+/// .LBB0_4:
+///  sub     rsp, 24
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rsp, +, 16], rax
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 3
+///  call    qword, ptr, [rip, +, _ZN10index_many25sorted_bound_check_failed17hde68f0a1bd1fc49dE@GOTPCREL]
 /// ```
 pub unsafe fn checked_generic(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
     generic::index_many_mut(slice, indices)
@@ -429,33 +440,32 @@ pub unsafe fn checked_generic(slice: &mut [Elem], indices: [usize; LEN]) -> [&mu
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_usize_trait:
-///  sub     rsp, 56
-///  mov     r10, qword, ptr, [r9]
-///  mov     rax, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  cmp     r9, r8
-///  jae     .LBB0_3
-///  cmp     r10, rax
-///  jae     .LBB0_3
-///  cmp     rax, r9
-///  jae     .LBB0_3
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rcx], r8
-///  mov     qword, ptr, [rcx, +, 8], rax
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 56
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  cmp     rax, rdx
+///  jae     .LBB0_4
+///  mov     r8, qword, ptr, [rcx]
+///  mov     r9, qword, ptr, [rcx, +, 8]
+///  cmp     r8, r9
+///  jae     .LBB0_4
+///  cmp     r9, rax
+///  jae     .LBB0_4
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  lea     rax, [rsi, +, 8*rax]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rdx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_3:
-///  mov     qword, ptr, [rsp, +, 32], r10
-///  mov     qword, ptr, [rsp, +, 40], rax
-///  mov     qword, ptr, [rsp, +, 48], r9
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 3
-///  call    index_many::sorted_bound_check_failed
-///  ud2
+/// .LBB0_4:
+///  sub     rsp, 24
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rsp, +, 16], rax
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 3
+///  call    qword, ptr, [rip, +, _ZN10index_many25sorted_bound_check_failed17hde68f0a1bd1fc49dE@GOTPCREL]
 /// ```
 pub unsafe fn checked_usize_trait(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
     slice_index::index_many_mut(slice, indices)
@@ -466,57 +476,50 @@ pub unsafe fn checked_usize_trait(slice: &mut [Elem], indices: [usize; LEN]) ->
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_range_trait:
-///  push    rsi
-///  push    rdi
+///  push    r14
 ///  push    rbx
-///  sub     rsp, 80
-///  movups  xmm0, xmmword, ptr, [r9]
-///  movups  xmm1, xmmword, ptr, [r9, +, 16]
-///  movups  xmm2, xmmword, ptr, [r9, +, 32]
-///  movaps  xmmword, ptr, [rsp, +, 32], xmm0
-///  movaps  xmmword, ptr, [rsp, +, 48], xmm1
-///  movaps  xmmword, ptr, [rsp, +, 64], xmm2
-///  mov     r11, qword, ptr, [rsp, +, 72]
-///  cmp     r11, r8
-///  ja      .LBB0_6
-///  mov     r10, qword, ptr, [rsp, +, 32]
-///  mov     rsi, qword, ptr, [rsp, +, 40]
-///  mov     r9, rsi
-///  sub     r9, r10
-///  jb      .LBB0_6
-///  mov     rax, qword, ptr, [rsp, +, 48]
-///  cmp     rsi, rax
-///  ja      .LBB0_6
-///  mov     rbx, qword, ptr, [rsp, +, 56]
-///  mov     rsi, rbx
-///  sub     rsi, rax
-///  jb      .LBB0_6
-///  mov     rdi, qword, ptr, [rsp, +, 64]
-///  cmp     rbx, rdi
-///  ja      .LBB0_6
-///  sub     r11, rdi
-///  jb      .LBB0_6
-///  lea     rbx, [rdx, +, 8*r10]
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     rdx, [rdx, +, 8*rdi]
-///  mov     qword, ptr, [rcx], rbx
-///  mov     qword, ptr, [rcx, +, 8], r9
-///  mov     qword, ptr, [rcx, +, 16], rax
-///  mov     qword, ptr, [rcx, +, 24], rsi
-///  mov     qword, ptr, [rcx, +, 32], rdx
-///  mov     qword, ptr, [rcx, +, 40], r11
-///  mov     rax, rcx
-///  add     rsp, 80
+///  push    rax
+///  mov     r8, qword, ptr, [rcx]
+///  mov     r10, qword, ptr, [rcx, +, 8]
+///  mov     rax, r10
+///  sub     rax, r8
+///  jb      .LBB0_7
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  cmp     r10, r9
+///  ja      .LBB0_7
+///  mov     rbx, qword, ptr, [rcx, +, 24]
+///  mov     r10, rbx
+///  sub     r10, r9
+///  jb      .LBB0_7
+///  mov     r11, qword, ptr, [rcx, +, 32]
+///  cmp     rbx, r11
+///  ja      .LBB0_7
+///  mov     r14, qword, ptr, [rcx, +, 40]
+///  mov     rbx, r14
+///  sub     rbx, r11
+///  jb      .LBB0_7
+///  cmp     r14, rdx
+///  ja      .LBB0_7
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  lea     rsi, [rsi, +, 8*r11]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rax
+///  mov     qword, ptr, [rdi, +, 16], rdx
+///  mov     qword, ptr, [rdi, +, 24], r10
+///  mov     qword, ptr, [rdi, +, 32], rsi
+///  mov     qword, ptr, [rdi, +, 40], rbx
+///  mov     rax, rdi
+///  add     rsp, 8
 ///  pop     rbx
-///  pop     rdi
-///  pop     rsi
+///  pop     r14
 ///  ret
-/// .LBB0_6:
-///  lea     r9, [rip, +, __unnamed_1]
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 3
-///  call    index_many::slice_index::ranges::range_check_fail
-///  ud2
+/// .LBB0_7:
+///  lea     rax, [rip, +, .Lanon.2a16a96b12112706cf0cc6e52bee891e.1]
+///  mov     esi, 3
+///  mov     rdi, rcx
+///  mov     rcx, rax
+///  call    qword, ptr, [rip, +, _ZN10index_many11slice_index6ranges16range_check_fail17h1c2bfee4c59eb102E@GOTPCREL]
 /// ```
 pub unsafe fn checked_range_trait(
     slice: &mut [Elem],
@@ -530,29 +533,28 @@ pub unsafe fn checked_range_trait(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_presorted:
-///  sub     rsp, 56
-///  mov     r11, qword, ptr, [r9]
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rax, qword, ptr, [r9, +, 16]
-///  cmp     rax, r8
-///  jae     .LBB0_1
-///  lea     r8, [rdx, +, 8*r11]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rax, [rdx, +, 8*rax]
-///  mov     qword, ptr, [rcx], r8
-///  mov     qword, ptr, [rcx, +, 8], r9
-///  mov     qword, ptr, [rcx, +, 16], rax
-///  mov     rax, rcx
-///  add     rsp, 56
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  cmp     rax, rdx
+///  jae     .LBB0_2
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rcx, qword, ptr, [rcx, +, 8]
+///  lea     rdx, [rsi, +, 8*rdx]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rax, [rsi, +, 8*rax]
+///  mov     qword, ptr, [rdi], rdx
+///  mov     qword, ptr, [rdi, +, 8], rcx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_1:
-///  mov     qword, ptr, [rsp, +, 32], r11
-///  mov     qword, ptr, [rsp, +, 40], r10
-///  mov     qword, ptr, [rsp, +, 48], rax
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 3
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_2:
+///  sub     rsp, 24
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rsp, +, 16], rax
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 3
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_presorted(
     slice: &mut [Elem],
@@ -566,39 +568,38 @@ pub unsafe fn checked_presorted(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted:
-///  sub     rsp, 56
-///  mov     rax, qword, ptr, [r9]
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  cmp     r9, r10
-///  je      .LBB0_6
-///  cmp     r9, rax
-///  je      .LBB0_6
-///  cmp     r9, r8
-///  jae     .LBB0_6
-///  cmp     r10, rax
-///  je      .LBB0_6
-///  cmp     rax, r8
-///  jae     .LBB0_6
-///  cmp     r10, r8
-///  jae     .LBB0_6
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rcx], rax
-///  mov     qword, ptr, [rcx, +, 8], r8
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 56
+///  mov     rax, qword, ptr, [rcx, +, 8]
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  cmp     r8, rax
+///  je      .LBB0_7
+///  mov     r9, qword, ptr, [rcx]
+///  cmp     r8, r9
+///  je      .LBB0_7
+///  cmp     r8, rdx
+///  jae     .LBB0_7
+///  cmp     rax, r9
+///  je      .LBB0_7
+///  cmp     r9, rdx
+///  jae     .LBB0_7
+///  cmp     rax, rdx
+///  jae     .LBB0_7
+///  lea     rcx, [rsi, +, 8*r9]
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rdx, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rax
+///  mov     qword, ptr, [rdi, +, 16], rdx
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_6:
-///  mov     qword, ptr, [rsp, +, 32], rax
-///  mov     qword, ptr, [rsp, +, 40], r10
-///  mov     qword, ptr, [rsp, +, 48], r9
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 3
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_7:
+///  sub     rsp, 24
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rsp, +, 16], rax
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 3
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_unsorted(
     slice: &mut [Elem],
@@ -612,6 +613,7 @@ pub unsafe fn checked_unsorted(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_0:
+///  ret
 /// ```
 pub unsafe fn checked_unsorted_0(
     slice: &mut [Elem],
@@ -625,20 +627,17 @@ pub unsafe fn checked_unsorted_0(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_1:
-///  sub     rsp, 40
-///  cmp     r8, rdx
-///  jae     .LBB0_1
-///  lea     rax, [rcx, +, 8*r8]
-///  add     rsp, 40
+///  cmp     rdx, rsi
+///  jae     .LBB0_2
+///  lea     rax, [rdi, +, 8*rdx]
 ///  ret
-/// .LBB0_1:
-///  mov     rax, rdx
-///  mov     qword, ptr, [rsp, +, 32], r8
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 1
-///  mov     r8, rax
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_2:
+///  push    rax
+///  mov     qword, ptr, [rsp], rdx
+///  mov     rdi, rsp
+///  mov     rdx, rsi
+///  mov     esi, 1
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_unsorted_1(
     slice: &mut [Elem],
@@ -652,35 +651,27 @@ pub unsafe fn checked_unsorted_1(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_2:
-///  push    rsi
-///  sub     rsp, 48
-///  mov     r10, rdx
+///  mov     rax, qword, ptr, [rcx]
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  cmp     r8, rax
+///  je      .LBB0_4
+///  cmp     rax, rdx
+///  jae     .LBB0_4
 ///  cmp     r8, rdx
-///  setb    dl
-///  cmp     r9, r10
-///  setb    al
-///  and     al, dl
-///  cmp     r9, r8
-///  setne   r11b
-///  lea     rdx, [rcx, +, 8*r9]
-///  xor     esi, esi
-///  test    r11b, al
-///  cmove   rdx, rsi
-///  lea     rax, [rcx, +, 8*r8]
-///  cmove   rax, rsi
-///  test    rax, rax
-///  je      .LBB0_1
-///  add     rsp, 48
-///  pop     rsi
+///  jae     .LBB0_4
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rcx, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rdi], rax
+///  mov     qword, ptr, [rdi, +, 8], rcx
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_1:
-///  mov     qword, ptr, [rsp, +, 32], r8
-///  mov     qword, ptr, [rsp, +, 40], r9
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 2
-///  mov     r8, r10
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_4:
+///  sub     rsp, 24
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 2
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_unsorted_2(
     slice: &mut [Elem],
@@ -694,39 +685,38 @@ pub unsafe fn checked_unsorted_2(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_3:
-///  sub     rsp, 56
-///  mov     rax, qword, ptr, [r9]
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  cmp     r9, r10
-///  je      .LBB0_6
-///  cmp     r9, rax
-///  je      .LBB0_6
-///  cmp     r9, r8
-///  jae     .LBB0_6
-///  cmp     r10, rax
-///  je      .LBB0_6
-///  cmp     rax, r8
-///  jae     .LBB0_6
-///  cmp     r10, r8
-///  jae     .LBB0_6
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rcx], rax
-///  mov     qword, ptr, [rcx, +, 8], r8
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 56
+///  mov     rax, qword, ptr, [rcx, +, 8]
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  cmp     r8, rax
+///  je      .LBB0_7
+///  mov     r9, qword, ptr, [rcx]
+///  cmp     r8, r9
+///  je      .LBB0_7
+///  cmp     r8, rdx
+///  jae     .LBB0_7
+///  cmp     rax, r9
+///  je      .LBB0_7
+///  cmp     r9, rdx
+///  jae     .LBB0_7
+///  cmp     rax, rdx
+///  jae     .LBB0_7
+///  lea     rcx, [rsi, +, 8*r9]
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rdx, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rax
+///  mov     qword, ptr, [rdi, +, 16], rdx
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_6:
-///  mov     qword, ptr, [rsp, +, 32], rax
-///  mov     qword, ptr, [rsp, +, 40], r10
-///  mov     qword, ptr, [rsp, +, 48], r9
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 3
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_7:
+///  sub     rsp, 24
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rsp, +, 16], rax
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 3
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_unsorted_3(
     slice: &mut [Elem],
@@ -740,51 +730,49 @@ pub unsafe fn checked_unsorted_3(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_4:
-///  sub     rsp, 72
-///  mov     rax, qword, ptr, [r9]
-///  mov     r11, qword, ptr, [r9, +, 8]
-///  mov     r10, qword, ptr, [r9, +, 16]
-///  mov     r9, qword, ptr, [r9, +, 24]
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     r8, qword, ptr, [rcx, +, 24]
+///  cmp     r8, rax
+///  je      .LBB0_11
+///  mov     r9, qword, ptr, [rcx, +, 8]
+///  cmp     r8, r9
+///  je      .LBB0_11
+///  mov     r10, qword, ptr, [rcx]
+///  cmp     r8, r10
+///  je      .LBB0_11
+///  cmp     r8, rdx
+///  jae     .LBB0_11
+///  cmp     rax, r9
+///  je      .LBB0_11
+///  cmp     rax, r10
+///  je      .LBB0_11
+///  cmp     rax, rdx
+///  jae     .LBB0_11
 ///  cmp     r9, r10
-///  je      .LBB0_10
-///  cmp     r9, r11
-///  je      .LBB0_10
-///  cmp     r9, rax
-///  je      .LBB0_10
-///  cmp     r9, r8
-///  jae     .LBB0_10
-///  cmp     r10, r11
-///  je      .LBB0_10
-///  cmp     r10, rax
-///  je      .LBB0_10
-///  cmp     r10, r8
-///  jae     .LBB0_10
-///  cmp     r11, rax
-///  je      .LBB0_10
-///  cmp     rax, r8
-///  jae     .LBB0_10
-///  cmp     r11, r8
-///  jae     .LBB0_10
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     r8, [rdx, +, 8*r11]
-///  lea     r10, [rdx, +, 8*r10]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rcx], rax
-///  mov     qword, ptr, [rcx, +, 8], r8
-///  mov     qword, ptr, [rcx, +, 16], r10
-///  mov     qword, ptr, [rcx, +, 24], rdx
-///  mov     rax, rcx
-///  add     rsp, 72
+///  je      .LBB0_11
+///  cmp     r10, rdx
+///  jae     .LBB0_11
+///  cmp     r9, rdx
+///  jae     .LBB0_11
+///  lea     rcx, [rsi, +, 8*r10]
+///  lea     rdx, [rsi, +, 8*r9]
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rsi, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rdx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     qword, ptr, [rdi, +, 24], rsi
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_10:
-///  mov     qword, ptr, [rsp, +, 40], rax
-///  mov     qword, ptr, [rsp, +, 48], r11
-///  mov     qword, ptr, [rsp, +, 56], r10
-///  mov     qword, ptr, [rsp, +, 64], r9
-///  lea     rcx, [rsp, +, 40]
-///  mov     edx, 4
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_11:
+///  sub     rsp, 40
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movups  xmm1, xmmword, ptr, [rcx, +, 16]
+///  movaps  xmmword, ptr, [rsp, +, 16], xmm1
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 4
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_unsorted_4(
     slice: &mut [Elem],
@@ -798,15 +786,16 @@ pub unsafe fn checked_unsorted_4(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::unchecked_simple:
-///  mov     rcx, qword, ptr, [r9]
-///  mov     r8, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     r8, [rdx, +, 8*r8]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rax], rcx
-///  mov     qword, ptr, [rax, +, 8], r8
-///  mov     qword, ptr, [rax, +, 16], rdx
+///  mov     rax, rdi
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rdi, qword, ptr, [rcx, +, 8]
+///  mov     rcx, qword, ptr, [rcx, +, 16]
+///  lea     rdx, [rsi, +, 8*rdx]
+///  lea     rdi, [rsi, +, 8*rdi]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  mov     qword, ptr, [rax], rdx
+///  mov     qword, ptr, [rax, +, 8], rdi
+///  mov     qword, ptr, [rax, +, 16], rcx
 ///  ret
 /// ```
 pub unsafe fn unchecked_simple(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
@@ -818,15 +807,16 @@ pub unsafe fn unchecked_simple(slice: &mut [Elem], indices: [usize; LEN]) -> [&m
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::unchecked_generic:
-///  mov     rcx, qword, ptr, [r9]
-///  mov     r8, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     r8, [rdx, +, 8*r8]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rax], rcx
-///  mov     qword, ptr, [rax, +, 8], r8
-///  mov     qword, ptr, [rax, +, 16], rdx
+///  mov     rax, rdi
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rdi, qword, ptr, [rcx, +, 8]
+///  mov     rcx, qword, ptr, [rcx, +, 16]
+///  lea     rdx, [rsi, +, 8*rdx]
+///  lea     rdi, [rsi, +, 8*rdi]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  mov     qword, ptr, [rax], rdx
+///  mov     qword, ptr, [rax, +, 8], rdi
+///  mov     qword, ptr, [rax, +, 16], rcx
 ///  ret
 /// ```
 pub unsafe fn unchecked_generic(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
@@ -838,15 +828,16 @@ pub unsafe fn unchecked_generic(slice: &mut [Elem], indices: [usize; LEN]) -> [&
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::unchecked_usize_trait:
-///  mov     rcx, qword, ptr, [r9]
-///  mov     r8, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     r8, [rdx, +, 8*r8]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rax], rcx
-///  mov     qword, ptr, [rax, +, 8], r8
-///  mov     qword, ptr, [rax, +, 16], rdx
+///  mov     rax, rdi
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rdi, qword, ptr, [rcx, +, 8]
+///  mov     rcx, qword, ptr, [rcx, +, 16]
+///  lea     rdx, [rsi, +, 8*rdx]
+///  lea     rdi, [rsi, +, 8*rdi]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  mov     qword, ptr, [rax], rdx
+///  mov     qword, ptr, [rax, +, 8], rdi
+///  mov     qword, ptr, [rax, +, 16], rcx
 ///  ret
 /// ```
 pub unsafe fn unchecked_usize_trait(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
@@ -858,29 +849,25 @@ pub unsafe fn unchecked_usize_trait(slice: &mut [Elem], indices: [usize; LEN]) -
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::unchecked_range_trait:
-///  push    rsi
-///  push    rdi
-///  mov     rax, rcx
-///  mov     r8, qword, ptr, [r9]
-///  mov     rcx, qword, ptr, [r9, +, 8]
-///  mov     r10, qword, ptr, [r9, +, 16]
-///  mov     r11, qword, ptr, [r9, +, 24]
-///  mov     rdi, qword, ptr, [r9, +, 32]
-///  mov     rsi, qword, ptr, [r9, +, 40]
-///  lea     r9, [rdx, +, 8*r8]
-///  sub     rcx, r8
-///  lea     r8, [rdx, +, 8*r10]
-///  sub     r11, r10
-///  lea     rdx, [rdx, +, 8*rdi]
-///  sub     rsi, rdi
-///  mov     qword, ptr, [rax], r9
-///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     rax, rdi
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rdi, qword, ptr, [rcx, +, 8]
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  mov     r9, qword, ptr, [rcx, +, 24]
+///  mov     r10, qword, ptr, [rcx, +, 32]
+///  mov     rcx, qword, ptr, [rcx, +, 40]
+///  sub     rdi, rdx
+///  lea     rdx, [rsi, +, 8*rdx]
+///  sub     r9, r8
+///  lea     r8, [rsi, +, 8*r8]
+///  sub     rcx, r10
+///  lea     rsi, [rsi, +, 8*r10]
+///  mov     qword, ptr, [rax], rdx
+///  mov     qword, ptr, [rax, +, 8], rdi
 ///  mov     qword, ptr, [rax, +, 16], r8
-///  mov     qword, ptr, [rax, +, 24], r11
-///  mov     qword, ptr, [rax, +, 32], rdx
-///  mov     qword, ptr, [rax, +, 40], rsi
-///  pop     rdi
-///  pop     rsi
+///  mov     qword, ptr, [rax, +, 24], r9
+///  mov     qword, ptr, [rax, +, 32], rsi
+///  mov     qword, ptr, [rax, +, 40], rcx
 ///  ret
 /// ```
 pub unsafe fn unchecked_range_trait(
@@ -895,31 +882,27 @@ pub unsafe fn unchecked_range_trait(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::unwrap_option_simple:
-///  sub     rsp, 40
-///  mov     r10, qword, ptr, [r9, +, 16]
-///  cmp     r10, r8
-///  jae     .LBB0_3
-///  mov     r8, qword, ptr, [r9]
-///  mov     rax, qword, ptr, [r9, +, 8]
-///  cmp     r8, rax
-///  jae     .LBB0_3
-///  cmp     rax, r10
-///  jae     .LBB0_3
-///  lea     r8, [rdx, +, 8*r8]
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     rdx, [rdx, +, 8*r10]
-///  mov     qword, ptr, [rcx], r8
-///  mov     qword, ptr, [rcx, +, 8], rax
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 40
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  cmp     rax, rdx
+///  jae     .LBB0_4
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rcx, qword, ptr, [rcx, +, 8]
+///  cmp     rdx, rcx
+///  jae     .LBB0_4
+///  cmp     rcx, rax
+///  jae     .LBB0_4
+///  lea     rdx, [rsi, +, 8*rdx]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rax, [rsi, +, 8*rax]
+///  mov     qword, ptr, [rdi], rdx
+///  mov     qword, ptr, [rdi, +, 8], rcx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_3:
-///  lea     rcx, [rip, +, __unnamed_1]
-///  lea     r8, [rip, +, __unnamed_2]
-///  mov     edx, 43
-///  call    core::panicking::panic
-///  ud2
+/// .LBB0_4:
+///  push    rax
+///  lea     rdi, [rip, +, .Lanon.f375ed39c0766ec3adc3c8c71f860575.1]
+///  call    qword, ptr, [rip, +, _RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed@GOTPCREL]
 /// ```
 pub unsafe fn unwrap_option_simple(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
     simple::get_many_mut(slice, indices).unwrap()
@@ -930,31 +913,27 @@ pub unsafe fn unwrap_option_simple(slice: &mut [Elem], indices: [usize; LEN]) ->
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::unwrap_option_generic:
-///  sub     rsp, 40
-///  mov     r10, qword, ptr, [r9, +, 16]
-///  cmp     r10, r8
-///  jae     .LBB0_3
-///  mov     r8, qword, ptr, [r9]
-///  mov     rax, qword, ptr, [r9, +, 8]
-///  cmp     r8, rax
-///  jae     .LBB0_3
-///  cmp     rax, r10
-///  jae     .LBB0_3
-///  lea     r8, [rdx, +, 8*r8]
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     rdx, [rdx, +, 8*r10]
-///  mov     qword, ptr, [rcx], r8
-///  mov     qword, ptr, [rcx, +, 8], rax
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 40
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  cmp     rax, rdx
+///  jae     .LBB0_4
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rcx, qword, ptr, [rcx, +, 8]
+///  cmp     rdx, rcx
+///  jae     .LBB0_4
+///  cmp     rcx, rax
+///  jae     .LBB0_4
+///  lea     rdx, [rsi, +, 8*rdx]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rax, [rsi, +, 8*rax]
+///  mov     qword, ptr, [rdi], rdx
+///  mov     qword, ptr, [rdi, +, 8], rcx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_3:
-///  lea     rcx, [rip, +, __unnamed_1]
-///  lea     r8, [rip, +, __unnamed_2]
-///  mov     edx, 43
-///  call    core::panicking::panic
-///  ud2
+/// .LBB0_4:
+///  push    rax
+///  lea     rdi, [rip, +, .Lanon.df925d29b5805edc833c6ecc29adbfb1.1]
+///  call    qword, ptr, [rip, +, _RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed@GOTPCREL]
 /// ```
 pub unsafe fn unwrap_option_generic(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
     generic::get_many_mut(slice, indices).unwrap()
@@ -965,38 +944,35 @@ pub unsafe fn unwrap_option_generic(slice: &mut [Elem], indices: [usize; LEN]) -
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::unwrap_result:
-///  sub     rsp, 72
-///  mov     r10, qword, ptr, [r9]
-///  mov     rax, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
+///  mov     r9, qword, ptr, [rcx]
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  cmp     rax, rdx
+///  jae     .LBB0_4
 ///  cmp     r9, r8
-///  jae     .LBB4_3
-///  cmp     r10, rax
-///  jae     .LBB4_3
-///  cmp     rax, r9
-///  jae     .LBB4_3
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rcx], r8
-///  mov     qword, ptr, [rcx, +, 8], rax
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 72
-///  ret
-/// .LBB4_3:
-///  mov     qword, ptr, [rsp, +, 40], r10
-///  mov     qword, ptr, [rsp, +, 48], rax
-///  mov     qword, ptr, [rsp, +, 56], r9
-///  mov     qword, ptr, [rsp, +, 64], r8
-///  lea     rax, [rip, +, __unnamed_6]
-///  mov     qword, ptr, [rsp, +, 32], rax
-///  lea     rcx, [rip, +, __unnamed_7]
-///  lea     r9, [rip, +, __unnamed_8]
-///  lea     r8, [rsp, +, 40]
-///  mov     edx, 43
-///  call    core::result::unwrap_failed
-///  ud2
+///  jae     .LBB0_4
+///  cmp     r8, rax
+///  jae     .LBB0_4
+///  lea     rcx, [rsi, +, 8*r9]
+///  lea     rdx, [rsi, +, 8*r8]
+///  lea     rax, [rsi, +, 8*rax]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rdx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     rax, rdi
+///  ret
+/// .LBB0_4:
+///  sub     rsp, 40
+///  mov     qword, ptr, [rsp, +, 8], r9
+///  mov     qword, ptr, [rsp, +, 16], r8
+///  mov     qword, ptr, [rsp, +, 24], rax
+///  mov     qword, ptr, [rsp, +, 32], rdx
+///  lea     rdi, [rip, +, .Lanon.e91198ac0552bd1f425a9662434c9be1.4]
+///  lea     rcx, [rip, +, .Lanon.e91198ac0552bd1f425a9662434c9be1.3]
+///  lea     r8, [rip, +, .Lanon.e91198ac0552bd1f425a9662434c9be1.1]
+///  lea     rdx, [rsp, +, 8]
+///  mov     esi, 43
+///  call    qword, ptr, [rip, +, _RNvNtCsgEmfK2I1SDS_4core6result13unwrap_failed@GOTPCREL]
 /// ```
 pub unsafe fn unwrap_result(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
     simple_result::get_many_mut(slice, indices).unwrap()
@@ -1007,6 +983,7 @@ pub unsafe fn unwrap_result(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_specialized_0:
+///  ret
 /// ```
 pub unsafe fn checked_unsorted_specialized_0(
     slice: &mut [Elem],
@@ -1020,20 +997,17 @@ pub unsafe fn checked_unsorted_specialized_0(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_specialized_1:
-///  sub     rsp, 40
-///  cmp     r8, rdx
-///  jae     .LBB0_1
-///  lea     rax, [rcx, +, 8*r8]
-///  add     rsp, 40
+///  cmp     rdx, rsi
+///  jae     .LBB0_2
+///  lea     rax, [rdi, +, 8*rdx]
 ///  ret
-/// .LBB0_1:
-///  mov     rax, rdx
-///  mov     qword, ptr, [rsp, +, 32], r8
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 1
-///  mov     r8, rax
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_2:
+///  push    rax
+///  mov     qword, ptr, [rsp], rdx
+///  mov     rdi, rsp
+///  mov     rdx, rsi
+///  mov     esi, 1
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_unsorted_specialized_1(
     slice: &mut [Elem],
@@ -1047,35 +1021,27 @@ pub unsafe fn checked_unsorted_specialized_1(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_specialized_2:
-///  push    rsi
-///  sub     rsp, 48
-///  mov     r10, rdx
-///  cmp     r8, r9
-///  setne   dl
-///  cmp     r8, r10
-///  setb    al
-///  and     al, dl
-///  cmp     r9, r10
-///  setb    r11b
-///  lea     rdx, [rcx, +, 8*r9]
-///  xor     esi, esi
-///  test    r11b, al
-///  cmove   rdx, rsi
-///  lea     rax, [rcx, +, 8*r8]
-///  cmove   rax, rsi
-///  test    rax, rax
-///  je      .LBB0_1
-///  add     rsp, 48
-///  pop     rsi
+///  mov     rax, qword, ptr, [rcx, +, 8]
+///  cmp     rax, rdx
+///  jae     .LBB0_4
+///  mov     r8, qword, ptr, [rcx]
+///  cmp     r8, rdx
+///  jae     .LBB0_4
+///  cmp     r8, rax
+///  je      .LBB0_4
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rax, [rsi, +, 8*rax]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rax
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_1:
-///  mov     qword, ptr, [rsp, +, 32], r8
-///  mov     qword, ptr, [rsp, +, 40], r9
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 2
-///  mov     r8, r10
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_4:
+///  sub     rsp, 24
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 2
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_unsorted_specialized_2(
     slice: &mut [Elem],
@@ -1089,39 +1055,38 @@ pub unsafe fn checked_unsorted_specialized_2(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_specialized_3:
-///  sub     rsp, 56
-///  mov     rax, qword, ptr, [r9]
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  cmp     r9, r10
-///  je      .LBB0_6
-///  cmp     r9, rax
-///  je      .LBB0_6
-///  cmp     r9, r8
-///  jae     .LBB0_6
-///  cmp     r10, rax
-///  je      .LBB0_6
-///  cmp     rax, r8
-///  jae     .LBB0_6
-///  cmp     r10, r8
-///  jae     .LBB0_6
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rcx], rax
-///  mov     qword, ptr, [rcx, +, 8], r8
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 56
+///  mov     rax, qword, ptr, [rcx, +, 8]
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  cmp     r8, rax
+///  je      .LBB0_7
+///  mov     r9, qword, ptr, [rcx]
+///  cmp     r8, r9
+///  je      .LBB0_7
+///  cmp     r8, rdx
+///  jae     .LBB0_7
+///  cmp     rax, r9
+///  je      .LBB0_7
+///  cmp     r9, rdx
+///  jae     .LBB0_7
+///  cmp     rax, rdx
+///  jae     .LBB0_7
+///  lea     rcx, [rsi, +, 8*r9]
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rdx, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rax
+///  mov     qword, ptr, [rdi, +, 16], rdx
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_6:
-///  mov     qword, ptr, [rsp, +, 32], rax
-///  mov     qword, ptr, [rsp, +, 40], r10
-///  mov     qword, ptr, [rsp, +, 48], r9
-///  lea     rcx, [rsp, +, 32]
-///  mov     edx, 3
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_7:
+///  sub     rsp, 24
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rsp, +, 16], rax
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 3
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_unsorted_specialized_3(
     slice: &mut [Elem],
@@ -1135,51 +1100,49 @@ pub unsafe fn checked_unsorted_specialized_3(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::checked_unsorted_specialized_4:
-///  sub     rsp, 72
-///  mov     rax, qword, ptr, [r9]
-///  mov     r11, qword, ptr, [r9, +, 8]
-///  mov     r10, qword, ptr, [r9, +, 16]
-///  mov     r9, qword, ptr, [r9, +, 24]
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     r8, qword, ptr, [rcx, +, 24]
+///  cmp     r8, rax
+///  je      .LBB0_11
+///  mov     r9, qword, ptr, [rcx, +, 8]
+///  cmp     r8, r9
+///  je      .LBB0_11
+///  mov     r10, qword, ptr, [rcx]
+///  cmp     r8, r10
+///  je      .LBB0_11
+///  cmp     r8, rdx
+///  jae     .LBB0_11
+///  cmp     rax, r9
+///  je      .LBB0_11
+///  cmp     rax, r10
+///  je      .LBB0_11
+///  cmp     rax, rdx
+///  jae     .LBB0_11
 ///  cmp     r9, r10
-///  je      .LBB0_10
-///  cmp     r9, r11
-///  je      .LBB0_10
-///  cmp     r9, rax
-///  je      .LBB0_10
-///  cmp     r9, r8
-///  jae     .LBB0_10
-///  cmp     r10, r11
-///  je      .LBB0_10
-///  cmp     r10, rax
-///  je      .LBB0_10
-///  cmp     r10, r8
-///  jae     .LBB0_10
-///  cmp     r11, rax
-///  je      .LBB0_10
-///  cmp     rax, r8
-///  jae     .LBB0_10
-///  cmp     r11, r8
-///  jae     .LBB0_10
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     r8, [rdx, +, 8*r11]
-///  lea     r10, [rdx, +, 8*r10]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rcx], rax
-///  mov     qword, ptr, [rcx, +, 8], r8
-///  mov     qword, ptr, [rcx, +, 16], r10
-///  mov     qword, ptr, [rcx, +, 24], rdx
-///  mov     rax, rcx
-///  add     rsp, 72
+///  je      .LBB0_11
+///  cmp     r10, rdx
+///  jae     .LBB0_11
+///  cmp     r9, rdx
+///  jae     .LBB0_11
+///  lea     rcx, [rsi, +, 8*r10]
+///  lea     rdx, [rsi, +, 8*r9]
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rsi, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rdx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     qword, ptr, [rdi, +, 24], rsi
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_10:
-///  mov     qword, ptr, [rsp, +, 40], rax
-///  mov     qword, ptr, [rsp, +, 48], r11
-///  mov     qword, ptr, [rsp, +, 56], r10
-///  mov     qword, ptr, [rsp, +, 64], r9
-///  lea     rcx, [rsp, +, 40]
-///  mov     edx, 4
-///  call    index_many::bound_check_failed
-///  ud2
+/// .LBB0_11:
+///  sub     rsp, 40
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movups  xmm1, xmmword, ptr, [rcx, +, 16]
+///  movaps  xmmword, ptr, [rsp, +, 16], xmm1
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 4
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
 /// ```
 pub unsafe fn checked_unsorted_specialized_4(
     slice: &mut [Elem],
@@ -1193,30 +1156,30 @@ pub unsafe fn checked_unsorted_specialized_4(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_option:
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rcx, qword, ptr, [r9, +, 16]
-///  cmp     rcx, r10
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r9, r8
 ///  je      .LBB0_7
-///  mov     r9, qword, ptr, [r9]
-///  cmp     rcx, r9
+///  mov     rcx, qword, ptr, [rcx]
+///  cmp     r9, rcx
 ///  je      .LBB0_7
-///  cmp     rcx, r8
+///  cmp     r9, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r9
+///  cmp     r8, rcx
 ///  je      .LBB0_7
-///  cmp     r9, r8
+///  cmp     rcx, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r8
+///  cmp     r8, rdx
 ///  jae     .LBB0_7
-///  lea     r8, [rdx, +, 8*r9]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  mov     qword, ptr, [rax], r8
-///  mov     qword, ptr, [rax, +, 8], r9
-///  mov     qword, ptr, [rax, +, 16], rcx
-///  ret
+///  lea     rdi, [rsi, +, 8*rcx]
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     qword, ptr, [rax, +, 16], rdx
 /// .LBB0_7:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn std_option(slice: &mut [Elem], indices: [usize; LEN]) -> Option<[&mut Elem; LEN]> {
@@ -1228,37 +1191,33 @@ pub unsafe fn std_option(slice: &mut [Elem], indices: [usize; LEN]) -> Option<[&
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_option_unwrap:
-///  sub     rsp, 40
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     r11, qword, ptr, [r9, +, 16]
-///  cmp     r11, r10
-///  je      .LBB0_6
-///  mov     rax, qword, ptr, [r9]
-///  cmp     r11, rax
-///  je      .LBB0_6
-///  cmp     r11, r8
-///  jae     .LBB0_6
-///  cmp     r10, rax
-///  je      .LBB0_6
-///  cmp     rax, r8
-///  jae     .LBB0_6
-///  cmp     r10, r8
-///  jae     .LBB0_6
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rdx, [rdx, +, 8*r11]
-///  mov     qword, ptr, [rcx], rax
-///  mov     qword, ptr, [rcx, +, 8], r8
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 40
+///  mov     rax, qword, ptr, [rcx, +, 8]
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  cmp     r8, rax
+///  je      .LBB0_7
+///  mov     rcx, qword, ptr, [rcx]
+///  cmp     r8, rcx
+///  je      .LBB0_7
+///  cmp     r8, rdx
+///  jae     .LBB0_7
+///  cmp     rax, rcx
+///  je      .LBB0_7
+///  cmp     rcx, rdx
+///  jae     .LBB0_7
+///  cmp     rax, rdx
+///  jae     .LBB0_7
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rdx, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rax
+///  mov     qword, ptr, [rdi, +, 16], rdx
+///  mov     rax, rdi
 ///  ret
-/// .LBB0_6:
-///  lea     rcx, [rip, +, __unnamed_1]
-///  lea     r8, [rip, +, __unnamed_2]
-///  mov     edx, 43
-///  call    core::panicking::panic
-///  ud2
+/// .LBB0_7:
+///  push    rax
+///  lea     rdi, [rip, +, .Lanon.aaccd2130e97c7d9c0b7a845a8cba41a.1]
+///  call    qword, ptr, [rip, +, _RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed@GOTPCREL]
 /// ```
 pub unsafe fn std_option_unwrap(slice: &mut [Elem], indices: [usize; LEN]) -> [&mut Elem; LEN] {
     std_proposal::SliceExt::get_many_mut_opt(slice, indices).unwrap()
@@ -1269,30 +1228,30 @@ pub unsafe fn std_option_unwrap(slice: &mut [Elem], indices: [usize; LEN]) -> [&
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_result_simple:
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rcx, qword, ptr, [r9, +, 16]
-///  cmp     rcx, r10
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r9, r8
 ///  je      .LBB0_7
-///  mov     r9, qword, ptr, [r9]
-///  cmp     rcx, r9
+///  mov     rcx, qword, ptr, [rcx]
+///  cmp     r9, rcx
 ///  je      .LBB0_7
-///  cmp     rcx, r8
+///  cmp     r9, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r9
+///  cmp     r8, rcx
 ///  je      .LBB0_7
-///  cmp     r9, r8
+///  cmp     rcx, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r8
+///  cmp     r8, rdx
 ///  jae     .LBB0_7
-///  lea     r8, [rdx, +, 8*r9]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  mov     qword, ptr, [rax], r8
-///  mov     qword, ptr, [rax, +, 8], r9
-///  mov     qword, ptr, [rax, +, 16], rcx
-///  ret
+///  lea     rdi, [rsi, +, 8*rcx]
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     qword, ptr, [rax, +, 16], rdx
 /// .LBB0_7:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn std_result_simple(
@@ -1307,30 +1266,30 @@ pub unsafe fn std_result_simple(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_result_simple_option:
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rcx, qword, ptr, [r9, +, 16]
-///  cmp     rcx, r10
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r9, r8
 ///  je      .LBB0_7
-///  mov     r9, qword, ptr, [r9]
-///  cmp     rcx, r9
+///  mov     rcx, qword, ptr, [rcx]
+///  cmp     r9, rcx
 ///  je      .LBB0_7
-///  cmp     rcx, r8
+///  cmp     r9, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r9
+///  cmp     r8, rcx
 ///  je      .LBB0_7
-///  cmp     r9, r8
+///  cmp     rcx, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r8
+///  cmp     r8, rdx
 ///  jae     .LBB0_7
-///  lea     r8, [rdx, +, 8*r9]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  mov     qword, ptr, [rax], r8
-///  mov     qword, ptr, [rax, +, 8], r9
-///  mov     qword, ptr, [rax, +, 16], rcx
-///  ret
+///  lea     rdi, [rsi, +, 8*rcx]
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     qword, ptr, [rax, +, 16], rdx
 /// .LBB0_7:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn std_result_simple_option(
@@ -1345,40 +1304,37 @@ pub unsafe fn std_result_simple_option(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_result_simple_unwrap:
-///  sub     rsp, 56
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     r11, qword, ptr, [r9, +, 16]
-///  cmp     r11, r10
-///  je      .LBB2_6
-///  mov     rax, qword, ptr, [r9]
-///  cmp     r11, rax
-///  je      .LBB2_6
-///  cmp     r11, r8
-///  jae     .LBB2_6
-///  cmp     r10, rax
-///  je      .LBB2_6
-///  cmp     rax, r8
-///  jae     .LBB2_6
-///  cmp     r10, r8
-///  jae     .LBB2_6
-///  lea     rax, [rdx, +, 8*rax]
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rdx, [rdx, +, 8*r11]
-///  mov     qword, ptr, [rcx], rax
-///  mov     qword, ptr, [rcx, +, 8], r8
-///  mov     qword, ptr, [rcx, +, 16], rdx
-///  mov     rax, rcx
-///  add     rsp, 56
-///  ret
-/// .LBB2_6:
-///  lea     rax, [rip, +, __unnamed_2]
-///  mov     qword, ptr, [rsp, +, 32], rax
-///  lea     rcx, [rip, +, __unnamed_3]
-///  lea     r9, [rip, +, __unnamed_4]
-///  lea     r8, [rsp, +, 48]
-///  mov     edx, 43
-///  call    core::result::unwrap_failed
-///  ud2
+///  mov     rax, qword, ptr, [rcx, +, 8]
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  cmp     r8, rax
+///  je      .LBB0_7
+///  mov     rcx, qword, ptr, [rcx]
+///  cmp     r8, rcx
+///  je      .LBB0_7
+///  cmp     r8, rdx
+///  jae     .LBB0_7
+///  cmp     rax, rcx
+///  je      .LBB0_7
+///  cmp     rcx, rdx
+///  jae     .LBB0_7
+///  cmp     rax, rdx
+///  jae     .LBB0_7
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rdx, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rax
+///  mov     qword, ptr, [rdi, +, 16], rdx
+///  mov     rax, rdi
+///  ret
+/// .LBB0_7:
+///  push    rax
+///  lea     rdi, [rip, +, .Lanon.1d7a63fbffd813307b365f92606fadae.3]
+///  lea     rcx, [rip, +, .Lanon.1d7a63fbffd813307b365f92606fadae.2]
+///  lea     r8, [rip, +, .Lanon.1d7a63fbffd813307b365f92606fadae.1]
+///  lea     rdx, [rsp, +, 7]
+///  mov     esi, 43
+///  call    qword, ptr, [rip, +, _RNvNtCsgEmfK2I1SDS_4core6result13unwrap_failed@GOTPCREL]
 /// ```
 pub unsafe fn std_result_simple_unwrap(
     slice: &mut [Elem],
@@ -1392,38 +1348,40 @@ pub unsafe fn std_result_simple_unwrap(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_result_direct:
-///  mov     rcx, qword, ptr, [r9]
-///  xor     r10d, r10d
-///  cmp     rcx, r8
-///  jae     .LBB0_5
-///  mov     r11, qword, ptr, [r9, +, 8]
-///  cmp     r11, r8
-///  jae     .LBB0_5
-///  mov     r10b, 1
-///  cmp     r11, rcx
-///  je      .LBB0_5
-///  mov     r9, qword, ptr, [r9, +, 16]
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx]
+///  cmp     r8, rdx
+///  jae     .LBB0_7
+///  mov     r9, qword, ptr, [rcx, +, 8]
+///  cmp     r9, rdx
+///  jae     .LBB0_10
+///  mov     dil, 1
 ///  cmp     r9, r8
-///  jae     .LBB0_4
-///  cmp     r9, rcx
-///  je      .LBB0_5
-///  cmp     r9, r11
-///  je      .LBB0_5
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     r8, [rdx, +, 8*r11]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rax, +, 8], rcx
-///  mov     qword, ptr, [rax, +, 16], r8
-///  mov     qword, ptr, [rax, +, 24], rdx
-///  xor     ecx, ecx
-///  mov     byte, ptr, [rax], cl
+///  je      .LBB0_8
+///  mov     rcx, qword, ptr, [rcx, +, 16]
+///  cmp     rcx, rdx
+///  jae     .LBB0_10
+///  cmp     rcx, r8
+///  je      .LBB0_8
+///  cmp     rcx, r9
+///  je      .LBB0_8
+///  lea     rdx, [rsi, +, 8*r8]
+///  lea     rdi, [rsi, +, 8*r9]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  mov     qword, ptr, [rax], rdx
+///  mov     qword, ptr, [rax, +, 8], rdi
+///  mov     qword, ptr, [rax, +, 16], rcx
 ///  ret
-/// .LBB0_4:
-///  xor     r10d, r10d
-/// .LBB0_5:
-///  mov     byte, ptr, [rax, +, 1], r10b
-///  mov     cl, 1
-///  mov     byte, ptr, [rax], cl
+/// .LBB0_7:
+///  xor     edi, edi
+/// .LBB0_8:
+///  mov     byte, ptr, [rax, +, 8], dil
+///  mov     qword, ptr, [rax], 0
+///  ret
+/// .LBB0_10:
+///  xor     edi, edi
+///  mov     byte, ptr, [rax, +, 8], dil
+///  mov     qword, ptr, [rax], 0
 ///  ret
 /// ```
 pub unsafe fn std_result_direct(
@@ -1438,29 +1396,32 @@ pub unsafe fn std_result_direct(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_result_direct_option:
-///  mov     r10, qword, ptr, [r9]
-///  cmp     r10, r8
-///  jae     .LBB0_7
-///  mov     rcx, qword, ptr, [r9, +, 8]
+///  mov     rax, rdi
+///  mov     rdi, qword, ptr, [rcx]
+///  cmp     rdi, rdx
+///  jae     .LBB0_5
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  cmp     r8, rdx
+///  setae   r9b
+///  cmp     r8, rdi
+///  sete    r10b
+///  or      r10b, r9b
+///  jne     .LBB0_5
+///  mov     rcx, qword, ptr, [rcx, +, 16]
+///  cmp     rcx, rdx
+///  jae     .LBB0_5
+///  cmp     rcx, rdi
+///  je      .LBB0_5
 ///  cmp     rcx, r8
-///  jae     .LBB0_7
-///  cmp     rcx, r10
-///  je      .LBB0_7
-///  mov     r9, qword, ptr, [r9, +, 16]
-///  cmp     r9, r8
-///  jae     .LBB0_7
-///  cmp     r9, r10
-///  je      .LBB0_7
-///  cmp     r9, rcx
-///  je      .LBB0_7
-///  lea     r8, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  lea     rdx, [rdx, +, 8*r9]
-///  mov     qword, ptr, [rax], r8
-///  mov     qword, ptr, [rax, +, 8], rcx
-///  mov     qword, ptr, [rax, +, 16], rdx
+///  je      .LBB0_5
+///  lea     rdx, [rsi, +, 8*rdi]
+///  lea     rdi, [rsi, +, 8*r8]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  mov     qword, ptr, [rax], rdx
+///  mov     qword, ptr, [rax, +, 8], rdi
+///  mov     qword, ptr, [rax, +, 16], rcx
 ///  ret
-/// .LBB0_7:
+/// .LBB0_5:
 ///  mov     qword, ptr, [rax], 0
 ///  ret
 /// ```
@@ -1476,35 +1437,37 @@ pub unsafe fn std_result_direct_option(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_result_indirect:
-///  mov     r11, qword, ptr, [r9]
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rcx, qword, ptr, [r9, +, 16]
-///  cmp     rcx, r10
-///  je      .LBB0_7
-///  cmp     rcx, r11
-///  je      .LBB0_7
-///  cmp     rcx, r8
-///  jae     .LBB0_7
-///  cmp     r10, r11
-///  je      .LBB0_7
-///  cmp     r11, r8
-///  jae     .LBB0_7
-///  cmp     r10, r8
-///  jae     .LBB0_7
-///  lea     r8, [rdx, +, 8*r11]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  mov     qword, ptr, [rax, +, 8], r8
-///  mov     qword, ptr, [rax, +, 16], r9
-///  mov     qword, ptr, [rax, +, 24], rcx
-///  xor     ecx, ecx
-///  mov     qword, ptr, [rax], rcx
-///  ret
-/// .LBB0_7:
-///  mov     qword, ptr, [rax, +, 8], r11
-///  mov     qword, ptr, [rax, +, 16], r10
-///  mov     qword, ptr, [rax, +, 24], rcx
-///  mov     qword, ptr, [rax, +, 32], r8
+///  mov     rax, rdi
+///  mov     rdi, qword, ptr, [rcx, +, 8]
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  cmp     r8, rdi
+///  je      .LBB0_6
+///  mov     r9, qword, ptr, [rcx]
+///  cmp     r8, r9
+///  je      .LBB0_6
+///  cmp     r8, rdx
+///  jae     .LBB0_6
+///  cmp     rdi, r9
+///  je      .LBB0_6
+///  cmp     r9, rdx
+///  jae     .LBB0_6
+///  cmp     rdi, rdx
+///  jae     .LBB0_6
+///  lea     rcx, [rsi, +, 8*r9]
+///  lea     rdx, [rsi, +, 8*rdi]
+///  lea     rsi, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     qword, ptr, [rax, +, 16], rdx
+///  mov     qword, ptr, [rax, +, 24], rsi
+///  xor     ecx, ecx
+///  mov     qword, ptr, [rax], rcx
+///  ret
+/// .LBB0_6:
+///  mov     rsi, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rax, +, 24], rsi
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movups  xmmword, ptr, [rax, +, 8], xmm0
+///  mov     qword, ptr, [rax, +, 32], rdx
 ///  mov     ecx, 1
 ///  mov     qword, ptr, [rax], rcx
 ///  ret
@@ -1521,30 +1484,30 @@ pub unsafe fn std_result_indirect(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_result_indirect_option:
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rcx, qword, ptr, [r9, +, 16]
-///  cmp     rcx, r10
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r9, r8
 ///  je      .LBB0_7
-///  mov     r9, qword, ptr, [r9]
-///  cmp     rcx, r9
+///  mov     rcx, qword, ptr, [rcx]
+///  cmp     r9, rcx
 ///  je      .LBB0_7
-///  cmp     rcx, r8
+///  cmp     r9, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r9
+///  cmp     r8, rcx
 ///  je      .LBB0_7
-///  cmp     r9, r8
+///  cmp     rcx, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r8
+///  cmp     r8, rdx
 ///  jae     .LBB0_7
-///  lea     r8, [rdx, +, 8*r9]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  mov     qword, ptr, [rax], r8
-///  mov     qword, ptr, [rax, +, 8], r9
-///  mov     qword, ptr, [rax, +, 16], rcx
-///  ret
+///  lea     rdi, [rsi, +, 8*rcx]
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     qword, ptr, [rax, +, 16], rdx
 /// .LBB0_7:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn std_result_indirect_option(
@@ -1559,38 +1522,38 @@ pub unsafe fn std_result_indirect_option(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_result_indirect_niche:
-///  mov     r11, qword, ptr, [r9]
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rcx, qword, ptr, [r9, +, 16]
-///  cmp     rcx, r10
-///  je      .LBB0_7
-///  cmp     rcx, r11
-///  je      .LBB0_7
-///  cmp     rcx, r8
-///  jae     .LBB0_7
-///  cmp     r10, r11
-///  je      .LBB0_7
-///  cmp     r11, r8
-///  jae     .LBB0_7
-///  cmp     r10, r8
-///  jae     .LBB0_7
-///  lea     r8, [rdx, +, 8*r11]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  mov     qword, ptr, [rax, +, 8], r8
-///  mov     qword, ptr, [rax, +, 16], r9
-///  mov     qword, ptr, [rax, +, 24], rcx
-///  xor     ecx, ecx
-///  mov     qword, ptr, [rax], rcx
+///  mov     rax, rdi
+///  mov     rdi, qword, ptr, [rcx, +, 8]
+///  mov     r8, qword, ptr, [rcx, +, 16]
+///  cmp     r8, rdi
+///  je      .LBB0_6
+///  mov     r9, qword, ptr, [rcx]
+///  cmp     r8, r9
+///  je      .LBB0_6
+///  cmp     r8, rdx
+///  jae     .LBB0_6
+///  cmp     rdi, r9
+///  je      .LBB0_6
+///  cmp     r9, rdx
+///  jae     .LBB0_6
+///  cmp     rdi, rdx
+///  jae     .LBB0_6
+///  lea     rcx, [rsi, +, 8*r9]
+///  lea     rdx, [rsi, +, 8*rdi]
+///  lea     rsi, [rsi, +, 8*r8]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     qword, ptr, [rax, +, 16], rdx
+///  mov     qword, ptr, [rax, +, 24], rsi
+///  xor     edx, edx
+///  mov     qword, ptr, [rax], rdx
 ///  ret
-/// .LBB0_7:
-///  mov     qword, ptr, [rax, +, 8], r11
-///  mov     qword, ptr, [rax, +, 16], r10
-///  mov     qword, ptr, [rax, +, 24], rcx
-///  add     r8, 2
-///  mov     qword, ptr, [rax, +, 32], r8
-///  mov     ecx, 1
-///  mov     qword, ptr, [rax], rcx
+/// .LBB0_6:
+///  add     rdx, 2
+///  mov     rsi, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rax, +, 24], rsi
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movups  xmmword, ptr, [rax, +, 8], xmm0
+///  mov     qword, ptr, [rax], rdx
 ///  ret
 /// ```
 pub unsafe fn std_result_indirect_niche(
@@ -1605,30 +1568,30 @@ pub unsafe fn std_result_indirect_niche(
 /// # Assembly (x86_64)
 /// ```x86asm
 /// codegen_crate::std_result_indirect_niche_option:
-///  mov     r10, qword, ptr, [r9, +, 8]
-///  mov     rcx, qword, ptr, [r9, +, 16]
-///  cmp     rcx, r10
+///  mov     rax, rdi
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  xor     edi, edi
+///  cmp     r9, r8
 ///  je      .LBB0_7
-///  mov     r9, qword, ptr, [r9]
-///  cmp     rcx, r9
+///  mov     rcx, qword, ptr, [rcx]
+///  cmp     r9, rcx
 ///  je      .LBB0_7
-///  cmp     rcx, r8
+///  cmp     r9, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r9
+///  cmp     r8, rcx
 ///  je      .LBB0_7
-///  cmp     r9, r8
+///  cmp     rcx, rdx
 ///  jae     .LBB0_7
-///  cmp     r10, r8
+///  cmp     r8, rdx
 ///  jae     .LBB0_7
-///  lea     r8, [rdx, +, 8*r9]
-///  lea     r9, [rdx, +, 8*r10]
-///  lea     rcx, [rdx, +, 8*rcx]
-///  mov     qword, ptr, [rax], r8
-///  mov     qword, ptr, [rax, +, 8], r9
-///  mov     qword, ptr, [rax, +, 16], rcx
-///  ret
+///  lea     rdi, [rsi, +, 8*rcx]
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  mov     qword, ptr, [rax, +, 8], rcx
+///  mov     qword, ptr, [rax, +, 16], rdx
 /// .LBB0_7:
-///  mov     qword, ptr, [rax], 0
+///  mov     qword, ptr, [rax], rdi
 ///  ret
 /// ```
 pub unsafe fn std_result_indirect_niche_option(
@@ -1637,3 +1600,613 @@ pub unsafe fn std_result_indirect_niche_option(
 ) -> Option<[&mut Elem; LEN]> {
     std_proposal::SliceExt::get_many_mut_res_indirect_niche(slice, indices).ok()
 }
+
+/// Body: `{ cell::get_many_cells(slice, indices) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::option_cells:
+///  mov     r8, qword, ptr, [rcx]
+///  mov     r9, qword, ptr, [rcx, +, 8]
+///  cmp     r9, r8
+///  mov     r10, r8
+///  cmova   r10, r9
+///  mov     rax, rdi
+///  mov     rdi, qword, ptr, [rcx, +, 16]
+///  cmp     rdi, r10
+///  cmova   r10, rdi
+///  cmp     r10, rdx
+///  jae     .LBB0_1
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  lea     rsi, [rsi, +, 8*rdi]
+///  mov     qword, ptr, [rax, +, 8], rdx
+///  mov     qword, ptr, [rax, +, 16], rsi
+///  mov     qword, ptr, [rax], rcx
+///  ret
+/// .LBB0_1:
+///  xor     ecx, ecx
+///  mov     qword, ptr, [rax], rcx
+///  ret
+/// ```
+pub unsafe fn option_cells(
+    slice: &mut [Elem],
+    indices: [usize; LEN],
+) -> Option<[&Cell<Elem>; LEN]> {
+    cell::get_many_cells(slice, indices)
+}
+
+/// Body: `{ cell::index_many_cells(slice, indices) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::checked_cells:
+///  mov     rax, qword, ptr, [rcx]
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  cmp     r8, rax
+///  mov     r10, rax
+///  cmova   r10, r8
+///  cmp     r9, r10
+///  cmova   r10, r9
+///  cmp     r10, rdx
+///  jae     .LBB0_2
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r9]
+///  mov     qword, ptr, [rdi], rax
+///  mov     qword, ptr, [rdi, +, 8], rcx
+///  mov     qword, ptr, [rdi, +, 16], rdx
+///  mov     rax, rdi
+///  ret
+/// .LBB0_2:
+///  sub     rsp, 24
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     qword, ptr, [rsp, +, 16], rax
+///  movups  xmm0, xmmword, ptr, [rcx]
+///  movaps  xmmword, ptr, [rsp], xmm0
+///  mov     rdi, rsp
+///  mov     esi, 3
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
+/// ```
+pub unsafe fn checked_cells(slice: &mut [Elem], indices: [usize; LEN]) -> [&Cell<Elem>; LEN] {
+    cell::index_many_cells(slice, indices)
+}
+
+/// Body: `{ cell::index_many_cell_ranges(slice, indices) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::checked_cell_ranges:
+///  push    r14
+///  push    rbx
+///  push    rax
+///  mov     r8, qword, ptr, [rcx]
+///  mov     r9, qword, ptr, [rcx, +, 8]
+///  mov     rax, r9
+///  sub     rax, r8
+///  jb      .LBB0_7
+///  cmp     r9, rdx
+///  ja      .LBB0_7
+///  mov     r10, qword, ptr, [rcx, +, 16]
+///  mov     r11, qword, ptr, [rcx, +, 24]
+///  mov     r9, r11
+///  sub     r9, r10
+///  jb      .LBB0_7
+///  cmp     r11, rdx
+///  ja      .LBB0_7
+///  mov     rbx, qword, ptr, [rcx, +, 32]
+///  mov     r14, qword, ptr, [rcx, +, 40]
+///  mov     r11, r14
+///  sub     r11, rbx
+///  jb      .LBB0_7
+///  cmp     r14, rdx
+///  ja      .LBB0_7
+///  lea     rcx, [rsi, +, 8*r8]
+///  lea     rdx, [rsi, +, 8*r10]
+///  lea     rsi, [rsi, +, 8*rbx]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rax
+///  mov     qword, ptr, [rdi, +, 16], rdx
+///  mov     qword, ptr, [rdi, +, 24], r9
+///  mov     qword, ptr, [rdi, +, 32], rsi
+///  mov     qword, ptr, [rdi, +, 40], r11
+///  mov     rax, rdi
+///  add     rsp, 8
+///  pop     rbx
+///  pop     r14
+///  ret
+/// .LBB0_7:
+///  lea     rax, [rip, +, .Lanon.4a4cd8ee01769ebd4fea66c7b4def2fb.1]
+///  mov     esi, 3
+///  mov     rdi, rcx
+///  mov     rcx, rax
+///  call    qword, ptr, [rip, +, _ZN10index_many4cell16range_check_fail17h50d31802fcada2d6E@GOTPCREL]
+/// ```
+pub unsafe fn checked_cell_ranges(
+    slice: &mut [Elem],
+    indices: [Range<usize>; LEN],
+) -> [&[Cell<Elem>]; LEN] {
+    cell::index_many_cell_ranges(slice, indices)
+}
+
+/// Body: `{ generic::index_many_mut(slice, indices) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::checked_indices_for:
+///  mov     rax, qword, ptr, [rcx, +, 24]
+///  cmp     rdx, rax
+///  jne     .LBB0_2
+///  mov     rax, qword, ptr, [rcx, +, 16]
+///  mov     rdx, qword, ptr, [rcx]
+///  mov     rcx, qword, ptr, [rcx, +, 8]
+///  lea     rdx, [rsi, +, 8*rdx]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rax, [rsi, +, 8*rax]
+///  mov     qword, ptr, [rdi], rdx
+///  mov     qword, ptr, [rdi, +, 8], rcx
+///  mov     qword, ptr, [rdi, +, 16], rax
+///  mov     rax, rdi
+///  ret
+/// .LBB0_2:
+///  push    rax
+///  lea     rcx, [rip, +, .Lanon.3581d40a8edcc32aa9b77bfce6ec0d43.1]
+///  mov     rdi, rax
+///  mov     rsi, rdx
+///  mov     rdx, rcx
+///  call    qword, ptr, [rip, +, _ZN10index_many7generic11indices_for17len_mismatch_fail17hacbf64e3d0e1b152E@GOTPCREL]
+/// ```
+pub unsafe fn checked_indices_for(
+    slice: &mut [Elem],
+    indices: generic::IndicesFor<generic::UnsortedIndices<LEN>>,
+) -> [&mut Elem; LEN] {
+    generic::index_many_mut(slice, indices)
+}
+
+/// Body: `{ stencil.get_many_mut(slice, center) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::option_stencil:
+///  mov     rax, rdi
+///  cmp     r8, qword, ptr, [rsi, +, 24]
+///  jb      .LBB0_2
+///  xor     edi, edi
+///  sub     rcx, qword, ptr, [rsi, +, 32]
+///  cmovae  rdi, rcx
+///  cmp     r8, rdi
+///  jae     .LBB0_2
+///  mov     rcx, qword, ptr, [rsi]
+///  mov     rdi, qword, ptr, [rsi, +, 8]
+///  mov     rsi, qword, ptr, [rsi, +, 16]
+///  lea     rdx, [rdx, +, 8*r8]
+///  lea     rcx, [rdx, +, 8*rcx]
+///  lea     rdi, [rdx, +, 8*rdi]
+///  lea     rdx, [rdx, +, 8*rsi]
+///  mov     qword, ptr, [rax], rcx
+///  mov     qword, ptr, [rax, +, 8], rdi
+///  mov     qword, ptr, [rax, +, 16], rdx
+///  ret
+/// .LBB0_2:
+///  mov     qword, ptr, [rax], 0
+///  ret
+/// ```
+pub unsafe fn option_stencil(
+    stencil: stencil::Stencil<LEN>,
+    slice: &mut [Elem],
+    center: usize,
+) -> Option<[&mut Elem; LEN]> {
+    stencil.get_many_mut(slice, center)
+}
+
+/// Body: `{ stencil.index_many_mut(slice, center) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::checked_stencil:
+///  cmp     r8, qword, ptr, [rsi, +, 24]
+///  jb      .LBB0_4
+///  xor     eax, eax
+///  mov     r9, rcx
+///  sub     r9, qword, ptr, [rsi, +, 32]
+///  cmovae  rax, r9
+///  cmp     r8, rax
+///  jae     .LBB0_4
+///  mov     rax, qword, ptr, [rsi]
+///  lea     rdx, [rdx, +, 8*r8]
+///  shl     rax, 3
+///  add     rax, rdx
+///  je      .LBB0_4
+///  mov     rcx, qword, ptr, [rsi, +, 8]
+///  mov     rsi, qword, ptr, [rsi, +, 16]
+///  lea     rsi, [rdx, +, 8*rsi]
+///  lea     rcx, [rdx, +, 8*rcx]
+///  mov     qword, ptr, [rdi], rax
+///  mov     qword, ptr, [rdi, +, 8], rcx
+///  mov     qword, ptr, [rdi, +, 16], rsi
+///  mov     rax, rdi
+///  ret
+/// .LBB0_4:
+///  push    rax
+///  lea     rax, [rip, +, .Lanon.7dcd5bd2e7188760bdd797cfb9319269.1]
+///  mov     rdi, rsi
+///  mov     esi, 3
+///  mov     rdx, r8
+///  mov     r8, rax
+///  call    qword, ptr, [rip, +, _ZN10index_many7stencil18stencil_check_fail17h0309056f9c08cb7eE@GOTPCREL]
+/// ```
+pub unsafe fn checked_stencil(
+    stencil: stencil::Stencil<LEN>,
+    slice: &mut [Elem],
+    center: usize,
+) -> [&mut Elem; LEN] {
+    stencil.index_many_mut(slice, center)
+}
+
+/// Body: `{ stencil.get_many_mut(slice, x, y) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::option_stencil_2d:
+///  mov     rax, r9
+///  mov     r9, rdx
+///  cmp     r8, qword, ptr, [rsi, +, 144]
+///  setae   r10b
+///  mov     rdx, qword, ptr, [rsi, +, 136]
+///  mov     r11, rdx
+///  sub     r11, qword, ptr, [rsi, +, 152]
+///  cmp     r8, r11
+///  setb    r11b
+///  mul     rdx
+///  jo      .LBB0_5
+///  add     rax, r8
+///  jb      .LBB0_5
+///  cmp     rax, qword, ptr, [rsi, +, 120]
+///  jb      .LBB0_5
+///  and     r10b, r11b
+///  xor     edx, edx
+///  sub     rcx, qword, ptr, [rsi, +, 128]
+///  cmovae  rdx, rcx
+///  cmp     rax, rdx
+///  setae   cl
+///  xor     r10b, 1
+///  or      r10b, cl
+///  jne     .LBB0_5
+///  mov     rcx, qword, ptr, [rsi, +, 80]
+///  mov     rdx, qword, ptr, [rsi, +, 88]
+///  mov     r8, qword, ptr, [rsi, +, 96]
+///  mov     r10, qword, ptr, [rsi, +, 104]
+///  mov     rsi, qword, ptr, [rsi, +, 112]
+///  lea     rax, [r9, +, 8*rax]
+///  lea     rcx, [rax, +, 8*rcx]
+///  lea     rdx, [rax, +, 8*rdx]
+///  lea     r8, [rax, +, 8*r8]
+///  lea     r9, [rax, +, 8*r10]
+///  lea     rax, [rax, +, 8*rsi]
+///  mov     qword, ptr, [rdi], rcx
+///  mov     qword, ptr, [rdi, +, 8], rdx
+///  mov     qword, ptr, [rdi, +, 16], r8
+///  mov     qword, ptr, [rdi, +, 24], r9
+///  mov     qword, ptr, [rdi, +, 32], rax
+///  mov     rax, rdi
+///  ret
+/// .LBB0_5:
+///  mov     qword, ptr, [rdi], 0
+///  mov     rax, rdi
+///  ret
+/// ```
+pub unsafe fn option_stencil_2d(
+    stencil: stencil::Stencil2d<5>,
+    slice: &mut [Elem],
+    x: usize,
+    y: usize,
+) -> Option<[&mut Elem; 5]> {
+    stencil.get_many_mut(slice, x, y)
+}
+
+/// Body: `{ generic::get_many_mut(slice, indices) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::option_strided:
+///  mov     rax, rdi
+///  lea     r9, [r8, +, r8]
+///  lea     rdi, [rcx, +, 2*r8]
+///  cmp     rdi, r9
+///  setae   r9b
+///  test    r8, r8
+///  js      .LBB0_1
+///  test    r9b, r9b
+///  je      .LBB0_4
+/// .LBB0_3:
+///  test    r8, r8
+///  setne   r9b
+///  cmp     rdi, rdx
+///  setb    dl
+///  test    r9b, dl
+///  je      .LBB0_4
+///  lea     rcx, [rsi, +, 8*rcx]
+///  lea     rdx, [rcx, +, 8*r8]
+///  lea     rsi, [rdx, +, 8*r8]
+///  mov     qword, ptr, [rax], rcx
+///  mov     qword, ptr, [rax, +, 8], rdx
+///  mov     qword, ptr, [rax, +, 16], rsi
+///  ret
+/// .LBB0_1:
+///  xor     r9d, r9d
+///  test    r9b, r9b
+///  jne     .LBB0_3
+/// .LBB0_4:
+///  mov     qword, ptr, [rax], 0
+///  ret
+/// ```
+pub unsafe fn option_strided(
+    slice: &mut [Elem],
+    indices: generic::Strided<LEN>,
+) -> Option<[&mut Elem; LEN]> {
+    generic::get_many_mut(slice, indices)
+}
+
+/// Body: `{ generic::index_many_mut(slice, indices) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::checked_strided:
+///  lea     r9, [r8, +, r8]
+///  lea     rax, [rcx, +, 2*r8]
+///  cmp     rax, r9
+///  setae   r9b
+///  test    r8, r8
+///  js      .LBB0_1
+///  test    r9b, r9b
+///  je      .LBB0_6
+/// .LBB0_3:
+///  test    r8, r8
+///  je      .LBB0_6
+///  cmp     rax, rdx
+///  jae     .LBB0_6
+///  lea     rax, [rsi, +, 8*rcx]
+///  lea     rcx, [rax, +, 8*r8]
+///  lea     rdx, [rcx, +, 8*r8]
+///  mov     qword, ptr, [rdi], rax
+///  mov     qword, ptr, [rdi, +, 8], rcx
+///  mov     qword, ptr, [rdi, +, 16], rdx
+///  mov     rax, rdi
+///  ret
+/// .LBB0_1:
+///  xor     r9d, r9d
+///  test    r9b, r9b
+///  jne     .LBB0_3
+/// .LBB0_6:
+///  push    rax
+///  lea     r9, [rip, +, .Lanon.a06450519fd68b3d497455df7d350ca2.1]
+///  mov     rax, rdx
+///  mov     edx, 3
+///  mov     rdi, rcx
+///  mov     ecx, 1
+///  mov     rsi, r8
+///  mov     r8, rax
+///  call    qword, ptr, [rip, +, _ZN10index_many7generic7strided18strided_check_fail17hd4c9d113d95b1547E@GOTPCREL]
+/// ```
+pub unsafe fn checked_strided(
+    slice: &mut [Elem],
+    indices: generic::Strided<LEN>,
+) -> [&mut Elem; LEN] {
+    generic::index_many_mut(slice, indices)
+}
+
+/// Body: `{ slice_index::get_many_mut(slice, indices) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::option_const_range_trait:
+///  push    rbx
+///  mov     rax, rdi
+///  mov     rdi, qword, ptr, [rcx]
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     rcx, qword, ptr, [rcx, +, 16]
+///  mov     rbx, rdi
+///  add     rbx, 4
+///  mov     r9, -1
+///  cmovb   rbx, r9
+///  mov     r10, r8
+///  add     r10, 4
+///  cmovb   r10, r9
+///  mov     r11, rcx
+///  add     r11, 4
+///  cmovb   r11, r9
+///  xor     r9d, r9d
+///  cmp     rbx, r8
+///  ja      .LBB0_4
+///  cmp     r10, rcx
+///  ja      .LBB0_4
+///  cmp     r11, rdx
+///  ja      .LBB0_4
+///  lea     r9, [rsi, +, 8*rdi]
+///  lea     rdx, [rsi, +, 8*r8]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  mov     qword, ptr, [rax, +, 8], rdx
+///  mov     qword, ptr, [rax, +, 16], rcx
+/// .LBB0_4:
+///  mov     qword, ptr, [rax], r9
+///  pop     rbx
+///  ret
+/// ```
+pub unsafe fn option_const_range_trait(
+    slice: &mut [Elem],
+    indices: [slice_index::ConstRange<4>; LEN],
+) -> Option<[&mut [Elem; 4]; LEN]> {
+    slice_index::get_many_mut(slice, indices)
+}
+
+/// Body: `{ slice_index::index_many_mut(slice, indices) }`
+///
+/// # Assembly (x86_64)
+/// ```x86asm
+/// codegen_crate::checked_const_range_trait:
+///  push    rbx
+///  sub     rsp, 48
+///  mov     rax, qword, ptr, [rcx]
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     rcx, qword, ptr, [rcx, +, 16]
+///  mov     r11, rax
+///  add     r11, 4
+///  mov     rbx, -1
+///  cmovb   r11, rbx
+///  mov     r9, r8
+///  add     r9, 4
+///  cmovb   r9, rbx
+///  mov     r10, rcx
+///  add     r10, 4
+///  cmovb   r10, rbx
+///  mov     qword, ptr, [rsp], rax
+///  mov     qword, ptr, [rsp, +, 8], r11
+///  mov     qword, ptr, [rsp, +, 16], r8
+///  mov     qword, ptr, [rsp, +, 24], r9
+///  mov     qword, ptr, [rsp, +, 32], rcx
+///  mov     qword, ptr, [rsp, +, 40], r10
+///  cmp     r11, r8
+///  ja      .LBB0_4
+///  cmp     r9, rcx
+///  ja      .LBB0_4
+///  cmp     r10, rdx
+///  ja      .LBB0_4
+///  lea     rax, [rsi, +, 8*rax]
+///  lea     rdx, [rsi, +, 8*r8]
+///  lea     rcx, [rsi, +, 8*rcx]
+///  mov     qword, ptr, [rdi], rax
+///  mov     qword, ptr, [rdi, +, 8], rdx
+///  mov     qword, ptr, [rdi, +, 16], rcx
+///  mov     rax, rdi
+///  add     rsp, 48
+///  pop     rbx
+///  ret
+/// .LBB0_4:
+///  lea     rcx, [rip, +, .Lanon.5defe286ea9f00482e206388d9033ed0.1]
+///  mov     rdi, rsp
+///  mov     esi, 3
+///  call    qword, ptr, [rip, +, _ZN10index_many11slice_index6ranges16range_check_fail17h1c2bfee4c59eb102E@GOTPCREL]
+/// ```
+pub unsafe fn checked_const_range_trait(
+    slice: &mut [Elem],
+    indices: [slice_index::ConstRange<4>; LEN],
+) -> [&mut [Elem; 4]; LEN] {
+    slice_index::index_many_mut(slice, indices)
+}
+
+/// Body: `{ gather::try_gather(slice, indices) }`
+///
+/// # Assembly (x86_64, `-C target-cpu=x86-64-v3`)
+/// ```x86asm
+/// codegen_crate::option_gather:
+///  mov     rax, rdi
+///  mov     rdi, qword, ptr, [rcx]
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  mov     r10, qword, ptr, [rcx, +, 24]
+///  cmp     r8, rdi
+///  cmova   rdi, r8
+///  cmp     r9, rdi
+///  cmova   rdi, r9
+///  cmp     r10, rdi
+///  cmova   rdi, r10
+///  cmp     rdi, rdx
+///  jae     .LBB0_1
+///  vmovdqu ymm0, ymmword, ptr, [rcx]
+///  vpcmpeqd ymm1, ymm1, ymm1
+///  vpxor   xmm2, xmm2, xmm2
+///  vpgatherqq ymm2, qword, ptr, [rsi, +, 8*ymm0], ymm1
+///  vmovdqu ymmword, ptr, [rax, +, 8], ymm2
+///  mov     ecx, 1
+///  mov     qword, ptr, [rax], rcx
+///  vzeroupper
+///  ret
+/// .LBB0_1:
+///  xor     ecx, ecx
+///  mov     qword, ptr, [rax], rcx
+///  ret
+/// ```
+pub unsafe fn option_gather(slice: &[Elem], indices: [usize; 4]) -> Option<[Elem; 4]> {
+    gather::try_gather(slice, indices)
+}
+
+/// Body: `{ gather::gather(slice, indices) }`
+///
+/// # Assembly (x86_64, `-C target-cpu=x86-64-v3`)
+/// ```x86asm
+/// codegen_crate::checked_gather:
+///  mov     rax, qword, ptr, [rcx]
+///  mov     r8, qword, ptr, [rcx, +, 8]
+///  mov     r9, qword, ptr, [rcx, +, 16]
+///  mov     r10, qword, ptr, [rcx, +, 24]
+///  cmp     r8, rax
+///  cmova   rax, r8
+///  cmp     r9, rax
+///  cmova   rax, r9
+///  cmp     r10, rax
+///  cmova   rax, r10
+///  cmp     rax, rdx
+///  jae     .LBB0_2
+///  vmovdqu ymm0, ymmword, ptr, [rcx]
+///  vpcmpeqd ymm1, ymm1, ymm1
+///  vpxor   xmm2, xmm2, xmm2
+///  vpgatherqq ymm2, qword, ptr, [rsi, +, 8*ymm0], ymm1
+///  vmovdqu ymmword, ptr, [rdi], ymm2
+///  mov     rax, rdi
+///  vzeroupper
+///  ret
+/// .LBB0_2:
+///  push    rax
+///  mov     esi, 4
+///  mov     rdi, rcx
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
+/// ```
+pub unsafe fn checked_gather(slice: &[Elem], indices: [usize; 4]) -> [Elem; 4] {
+    gather::gather(slice, indices)
+}
+
+/// Body: `{ gather::scatter(slice, indices, values) }`
+///
+/// # Assembly (x86_64, `-C target-cpu=x86-64-v4`)
+/// ```x86asm
+/// codegen_crate::checked_scatter:
+///  sub     rsp, 40
+///  vmovups ymm0, ymmword, ptr, [rdx]
+///  vmovups ymmword, ptr, [rsp], ymm0
+///  vmovdqu ymm0, ymmword, ptr, [rdx]
+///  vpermq  ymm1, ymm0, 57
+///  vpbroadcastq ymm2, rsi
+///  vpcmpltuq k0, ymm0, ymm2
+///  vpermq  ymm0, ymm0, 231
+///  vpcmpneqq k1, ymm1, ymm0
+///  kshiftlb k0, k0, 4
+///  korb    k0, k1, k0
+///  kortestb k0, k0
+///  jae     .LBB0_4
+///  mov     rax, qword, ptr, [rdx]
+///  cmp     qword, ptr, [rdx, +, 8], rax
+///  je      .LBB0_4
+///  cmp     qword, ptr, [rdx, +, 16], rax
+///  je      .LBB0_4
+///  vmovdqu ymm0, ymmword, ptr, [rdx]
+///  vmovdqu ymm1, ymmword, ptr, [rcx]
+///  kxnorw  k1, k0, k0
+///  vpscatterqq qword, ptr, [rdi, +, 8*ymm0], {k1}, ymm1
+///  add     rsp, 40
+///  vzeroupper
+///  ret
+/// .LBB0_4:
+///  mov     rdi, rsp
+///  mov     rdx, rsi
+///  mov     esi, 4
+///  vzeroupper
+///  call    qword, ptr, [rip, +, _ZN10index_many18bound_check_failed17h9729565ddf6d2b87E@GOTPCREL]
+/// ```
+pub unsafe fn checked_scatter(
+    slice: &mut [Elem],
+    indices: generic::UnsortedIndices<4>,
+    values: [Elem; 4],
+) {
+    gather::scatter(slice, indices, values)
+}
@@ -0,0 +1,147 @@
+//! Functions for moving many elements out of an array at once.
+
+use crate::generic::Indices;
+
+/// Moves the elements at `indices` out of `arr`, and drops all other elements.
+///
+/// The indices are validated the same way as for [`crate::generic::index_many()`].
+/// Every element of `arr` is either returned or dropped exactly once, even if
+/// the `Drop` impl of one of the dropped elements panics.
+///
+/// # Panics
+/// Panics if the indices are invalid for an array of length `M`.
+///
+/// # Example
+/// ```
+/// use index_many::array::take_many;
+/// use index_many::generic::UnsortedIndices;
+///
+/// let arr = [String::from("a"), String::from("b"), String::from("c")];
+/// let [c, a] = take_many(arr, UnsortedIndices([2, 0]));
+/// assert_eq!(c, "c");
+/// assert_eq!(a, "a");
+/// ```
+pub fn take_many<T, I: Indices<N>, const N: usize, const M: usize>(
+    arr: [T; M],
+    indices: I,
+) -> [T; N] {
+    if !indices.is_valid(M) {
+        indices.cause_invalid_panic(M)
+    }
+
+    // The array of `Option`s owns all elements that are not taken,
+    // so its drop glue drops each of them exactly once, and
+    // continues with the next one if a `Drop` impl panics.
+    let mut slots = arr.map(Some);
+
+    // SAFETY: We checked that the indices are unique and in bounds.
+    let taken = unsafe { crate::take_many_internal(&mut slots, indices.to_raw_indices()) };
+
+    // NB: The remaining elements have to be dropped while `taken` is still
+    // a local, so that it gets dropped as well if one of them panics.
+    drop(slots);
+    taken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    use crate::generic::{PresortedIndices, UnsortedIndices};
+    use crate::test_util::counters;
+
+    #[test]
+    fn test_normal() {
+        let arr = [1, 2, 3, 4, 5];
+        assert_eq!(take_many(arr, [0, 2, 4]), [1, 3, 5]);
+    }
+
+    #[test]
+    fn test_unsorted() {
+        let arr = [1, 2, 3, 4, 5];
+        assert_eq!(take_many(arr, UnsortedIndices([4, 0, 3])), [5, 1, 4]);
+    }
+
+    #[test]
+    fn test_presorted() {
+        let arr = [1, 2, 3, 4, 5];
+        let indices = PresortedIndices::new([1, 3]).unwrap();
+        assert_eq!(take_many(arr, indices), [2, 4]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let arr = [1, 2, 3];
+        let [] = take_many(arr, []);
+    }
+
+    #[test]
+    fn test_all() {
+        let arr = [1, 2, 3];
+        assert_eq!(take_many(arr, UnsortedIndices([2, 1, 0])), [3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 3 is out of bounds of slice with len 3 (indices [0, 3], position 1)"
+    )]
+    fn test_oob() {
+        take_many([1, 2, 3], [0, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 1 appears more than once (indices [1, 0, 1], position 0 and 2)"
+    )]
+    fn test_duplicate() {
+        take_many([1, 2, 3], UnsortedIndices([1, 0, 1]));
+    }
+
+    #[test]
+    fn test_drops() {
+        let drops = Rc::new(Cell::new(0));
+        let arr = counters([1, 2, 3, 4, 5], &drops);
+
+        let [a, b] = take_many(arr, UnsortedIndices([3, 1]));
+        assert_eq!(drops.get(), 3);
+        assert_eq!((a.value, b.value), (4, 2));
+
+        drop((a, b));
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_invalid_drops() {
+        let drops = Rc::new(Cell::new(0));
+        let arr = counters([1, 2, 3], &drops);
+
+        let res = catch_unwind(AssertUnwindSafe(|| take_many(arr, [1, 5])));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn test_panicking_drop() {
+        struct PanicOnDrop(i32, Rc<Cell<usize>>);
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+                if self.0 == 0 {
+                    panic!("element 0 panics");
+                }
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let arr = [0, 1, 2, 3].map(|i| PanicOnDrop(i, drops.clone()));
+
+        let res = catch_unwind(AssertUnwindSafe(|| take_many(arr, [2])));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 4);
+    }
+}
@@ -0,0 +1,220 @@
+//! Validation of many index groups at once.
+//!
+//! [`ValidatedBatch`] checks a whole list of index groups against a slice
+//! length up front. Visiting the groups afterwards needs only a single
+//! comparison of the slice length, no matter how many groups there are.
+//!
+//! # Example
+//! ```
+//! use index_many::batch::ValidatedBatch;
+//!
+//! // Edges of a graph, validated when building it
+//! let edges = [[0, 1], [1, 2], [2, 0]];
+//! let mut weights = vec![1, 2, 3];
+//! let batch = ValidatedBatch::new(&edges, weights.len()).unwrap();
+//!
+//! batch.for_each_mut(&mut weights, |[u, v]| *v += *u);
+//! assert_eq!(weights, vec![7, 3, 6]);
+//! ```
+
+/// A list of index groups, each of which has been checked to be in bounds
+/// of slices of a fixed length and to contain no duplicates.
+#[derive(Copy, Clone, Debug)]
+pub struct ValidatedBatch<'a, const N: usize> {
+    groups: &'a [[usize; N]],
+    len: usize,
+}
+
+#[derive(Debug)]
+pub struct ValidatedBatchError {
+    _private: (),
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn len_mismatch_fail(expected: usize, len: usize) -> ! {
+    panic!(
+        "Batch was validated for a slice with len {}, but used with a slice with len {}",
+        expected, len
+    );
+}
+
+impl<'a, const N: usize> ValidatedBatch<'a, N> {
+    /// Validates every group of `groups` for slices with length `len`.
+    ///
+    /// All indices are checked against `len` in a single pass computing their
+    /// maximum, and each group is checked for duplicates. Neither check exits
+    /// early, so both are straightforward for the compiler to vectorize.
+    pub fn new(groups: &'a [[usize; N]], len: usize) -> Result<Self, ValidatedBatchError> {
+        let mut valid = true;
+
+        if N != 0 && !groups.is_empty() {
+            let max = groups.iter().flatten().fold(0, |max, &idx| max.max(idx));
+            valid &= max < len;
+        }
+
+        for group in groups {
+            for i in 0..N {
+                for j in 0..i {
+                    valid &= group[i] != group[j];
+                }
+            }
+        }
+
+        if valid {
+            Ok(Self { groups, len })
+        } else {
+            Err(ValidatedBatchError { _private: () })
+        }
+    }
+
+    /// The slice length the groups have been validated against.
+    pub fn slice_len(&self) -> usize {
+        self.len
+    }
+
+    pub fn groups(&self) -> &'a [[usize; N]] {
+        self.groups
+    }
+
+    /// Calls `f` with the elements of each group, in order.
+    ///
+    /// # Panics
+    /// Panics if `slice.len()` differs from [`ValidatedBatch::slice_len()`].
+    pub fn for_each<T, F>(&self, slice: &[T], mut f: F)
+    where
+        F: FnMut([&T; N]),
+    {
+        if slice.len() != self.len {
+            len_mismatch_fail(self.len, slice.len());
+        }
+        for &group in self.groups {
+            // SAFETY: The group is in bounds of slices of this length.
+            unsafe { f(crate::get_many_internal(slice, group)) };
+        }
+    }
+
+    /// Calls `f` with mutable references to the elements of each group, in
+    /// order.
+    ///
+    /// # Panics
+    /// Panics if `slice.len()` differs from [`ValidatedBatch::slice_len()`].
+    pub fn for_each_mut<T, F>(&self, slice: &mut [T], mut f: F)
+    where
+        F: FnMut([&mut T; N]),
+    {
+        if slice.len() != self.len {
+            len_mismatch_fail(self.len, slice.len());
+        }
+        let slice: *mut [T] = slice;
+        for &group in self.groups {
+            // SAFETY: The group is unique and in bounds of slices of this
+            // length. The references handed to `f` can not outlive the call,
+            // so they never alias those of the next group.
+            unsafe { f(crate::get_many_internal_mut(slice, group)) };
+        }
+    }
+
+    /// Calls `f` with mutable references to the elements of the groups at
+    /// `positions` in [`ValidatedBatch::groups()`], in the order of
+    /// `positions`.
+    ///
+    /// This runs a single wave of [`crate::par::schedule_disjoint()`].
+    ///
+    /// # Panics
+    /// Panics if `slice.len()` differs from [`ValidatedBatch::slice_len()`],
+    /// or if any position is out of bounds of the groups.
+    pub fn for_each_mut_at<T, F>(&self, slice: &mut [T], positions: &[usize], mut f: F)
+    where
+        F: FnMut([&mut T; N]),
+    {
+        if slice.len() != self.len {
+            len_mismatch_fail(self.len, slice.len());
+        }
+        let slice: *mut [T] = slice;
+        for &pos in positions {
+            let group = self.groups[pos];
+            // SAFETY: Like in `for_each_mut`.
+            unsafe { f(crate::get_many_internal_mut(slice, group)) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValidatedBatch;
+
+    #[test]
+    fn test_mut_normal() {
+        let groups = [[0, 2], [3, 1], [2, 3]];
+        let mut v = vec![1, 2, 3, 4];
+        let batch = ValidatedBatch::new(&groups, v.len()).unwrap();
+        batch.for_each_mut(&mut v, |[a, b]| std::mem::swap(a, b));
+        assert_eq!(v, vec![3, 4, 2, 1]);
+        assert_eq!(batch.groups(), &groups);
+        assert_eq!(batch.slice_len(), 4);
+    }
+
+    #[test]
+    fn test_mut_at() {
+        let groups = [[0, 1], [1, 2], [2, 3]];
+        let mut v = vec![1, 2, 3, 4];
+        let batch = ValidatedBatch::new(&groups, v.len()).unwrap();
+        batch.for_each_mut_at(&mut v, &[2, 0], |[a, b]| *b += *a);
+        assert_eq!(v, vec![1, 3, 3, 7]);
+        batch.for_each_mut_at(&mut v, &[], |[_, _]| unreachable!());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_mut_at_oob() {
+        let groups = [[0, 1], [1, 2], [2, 3]];
+        let batch = ValidatedBatch::new(&groups, 4).unwrap();
+        batch.for_each_mut_at(&mut [1, 2, 3, 4], &[0, 3], |[_, _]| {});
+    }
+
+    #[test]
+    fn test_ref_normal() {
+        let groups = [[0, 1, 2], [4, 3, 2]];
+        let v = vec![1, 2, 3, 4, 5];
+        let batch = ValidatedBatch::new(&groups, v.len()).unwrap();
+        let mut sums = vec![];
+        batch.for_each(&v, |[a, b, c]| sums.push(a * 100 + b * 10 + c));
+        assert_eq!(sums, vec![123, 543]);
+    }
+
+    #[test]
+    fn test_reuse_across_slices() {
+        let groups = [[0, 1]];
+        let batch = ValidatedBatch::new(&groups, 2).unwrap();
+        let mut v = vec![1, 2];
+        let mut w = vec![3, 4];
+        for s in [&mut v, &mut w] {
+            batch.for_each_mut(s, |[a, b]| *a += *b);
+        }
+        assert_eq!(v, vec![3, 2]);
+        assert_eq!(w, vec![7, 4]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(ValidatedBatch::new(&[[0, 1], [1, 3]], 3).is_err());
+        assert!(ValidatedBatch::new(&[[0, 1], [2, 2]], 3).is_err());
+        assert!(ValidatedBatch::new(&[[0, 1, 0]], 3).is_err());
+        assert!(ValidatedBatch::new(&[[0, 1], [2, 1]], 3).is_ok());
+        assert!(ValidatedBatch::<2>::new(&[], 0).is_ok());
+        assert!(ValidatedBatch::new(&[[], []], 0).is_ok());
+        assert!(ValidatedBatch::new(&[[0]], 0).is_err());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Batch was validated for a slice with len 3, but used with a slice with len 4"
+    )]
+    fn test_len_mismatch() {
+        let groups = [[0, 1]];
+        let batch = ValidatedBatch::new(&groups, 3).unwrap();
+        batch.for_each_mut(&mut [1, 2, 3, 4], |[_, _]| {});
+    }
+}
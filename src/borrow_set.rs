@@ -0,0 +1,592 @@
+//! A slice wrapper that hands out disjoint mutable references one at a
+//! time, checking at runtime that no element is handed out twice.
+//!
+//! This is useful if the indices are not known up front, e.g. if the next
+//! index depends on the value of the previous element.
+//!
+//! # Example
+//! ```
+//! use index_many::borrow_set::{BorrowSet, TakeError};
+//!
+//! // A linked list stored in a slice: (value, next)
+//! let mut nodes = vec![(1, 2), (2, 0), (3, 1)];
+//! let set = BorrowSet::new(&mut nodes);
+//!
+//! let a = set.take(0).unwrap();
+//! let b = set.take(a.1).unwrap();
+//! std::mem::swap(&mut a.0, &mut b.0);
+//! assert_eq!(set.take(2).unwrap_err(), TakeError::AlreadyTaken);
+//! assert_eq!(nodes, vec![(3, 2), (2, 0), (1, 1)]);
+//! ```
+//!
+//! The references borrow the `BorrowSet`, so they have to be gone before it
+//! can be [reset](BorrowSet::reset):
+//! ```compile_fail
+//! use index_many::borrow_set::BorrowSet;
+//!
+//! let mut v = vec![1, 2, 3];
+//! let mut set = BorrowSet::new(&mut v);
+//! let a = set.take(0).unwrap();
+//! set.reset();
+//! let b = set.take(0).unwrap();
+//! *a += *b;
+//! ```
+//!
+//! [`AtomicBorrowSet`] is a variant that can be shared between threads.
+
+use std::cell::Cell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut, Range};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Slices up to this length keep their bitmap inline.
+const INLINE_BITS: usize = 128;
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// One bit per element of the slice, set if the element has been taken.
+enum Bitmap {
+    Inline([Cell<u64>; INLINE_BITS / WORD_BITS]),
+    Heap(Box<[Cell<u64>]>),
+}
+
+impl Bitmap {
+    fn new(len: usize) -> Self {
+        if len <= INLINE_BITS {
+            Bitmap::Inline(Default::default())
+        } else {
+            Bitmap::Heap((0..len.div_ceil(WORD_BITS)).map(|_| Cell::new(0)).collect())
+        }
+    }
+
+    fn words(&self) -> &[Cell<u64>] {
+        match self {
+            Bitmap::Inline(words) => words,
+            Bitmap::Heap(words) => words,
+        }
+    }
+
+    #[inline]
+    fn get(&self, idx: usize) -> bool {
+        self.words()[idx / WORD_BITS].get() & (1 << (idx % WORD_BITS)) != 0
+    }
+
+    #[inline]
+    fn set(&self, idx: usize) {
+        let word = &self.words()[idx / WORD_BITS];
+        word.set(word.get() | (1 << (idx % WORD_BITS)));
+    }
+
+    fn clear(&mut self) {
+        for word in self.words() {
+            word.set(0);
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TakeError {
+    /// The element, or an element of the range, has already been taken.
+    AlreadyTaken,
+    /// The index or range is out of bounds of the slice.
+    OutOfBounds,
+    /// The same index appears more than once in a single request, so
+    /// retrying it can never succeed.
+    Duplicate,
+}
+
+/// A mutable slice, whose elements can be taken as mutable references one
+/// at a time through a shared reference to the set.
+///
+/// Each element can be taken at most once until the set is
+/// [reset](BorrowSet::reset). Which elements have been taken is tracked in
+/// a bitmap, which is stored inline for slices of up to 128 elements, and on
+/// the heap otherwise.
+///
+/// The references handed out borrow the set, not the underlying slice, since
+/// resetting the set has to invalidate them. Use
+/// [`BorrowSet::into_inner()`] to get the slice back.
+pub struct BorrowSet<'a, T> {
+    slice: *mut [T],
+    taken: Bitmap,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+// SAFETY: The set has exclusive access to the slice, and all references
+// handed out borrow the set, so they can't be used while it is sent.
+unsafe impl<T: Send> Send for BorrowSet<'_, T> {}
+
+impl<'a, T> BorrowSet<'a, T> {
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            taken: Bitmap::new(slice.len()),
+            slice,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the element at `idx` has been taken.
+    ///
+    /// # Panics
+    /// Panics if `idx` is out of bounds.
+    pub fn is_taken(&self, idx: usize) -> bool {
+        assert!(idx < self.len(), "index out of bounds");
+        self.taken.get(idx)
+    }
+
+    /// Takes the element at `idx`, if it is in bounds and has not been
+    /// taken yet.
+    // The bitmap ensures that each element is handed out only once.
+    #[allow(clippy::mut_from_ref)]
+    pub fn take(&self, idx: usize) -> Result<&mut T, TakeError> {
+        if idx >= self.len() {
+            return Err(TakeError::OutOfBounds);
+        }
+        if self.taken.get(idx) {
+            return Err(TakeError::AlreadyTaken);
+        }
+        self.taken.set(idx);
+
+        // SAFETY: The index is in bounds, and the element has not been
+        // handed out since the set was created or last reset.
+        unsafe { Ok(&mut *crate::get_many_internal_mut(self.slice, [idx])[0]) }
+    }
+
+    /// Takes the elements in `range`, if it is in bounds and none of them
+    /// have been taken yet.
+    ///
+    /// Taking an empty range always succeeds if it is in bounds.
+    #[allow(clippy::mut_from_ref)]
+    pub fn take_range(&self, range: Range<usize>) -> Result<&mut [T], TakeError> {
+        if range.start > range.end || range.end > self.len() {
+            return Err(TakeError::OutOfBounds);
+        }
+        if range.clone().any(|idx| self.taken.get(idx)) {
+            return Err(TakeError::AlreadyTaken);
+        }
+        for idx in range.clone() {
+            self.taken.set(idx);
+        }
+
+        // SAFETY: The range is in bounds, and none of its elements have been
+        // handed out since the set was created or last reset.
+        unsafe { Ok(crate::get_many_internal_mut(self.slice, [range])[0]) }
+    }
+
+    /// Makes all elements available to be taken again.
+    pub fn reset(&mut self) {
+        self.taken.clear();
+    }
+
+    pub fn into_inner(self) -> &'a mut [T] {
+        // SAFETY: The set had exclusive access to the slice for `'a`, and all
+        // references handed out are gone since `self` is consumed.
+        unsafe { &mut *self.slice }
+    }
+}
+
+/// A mutable slice, whose elements can be claimed by many threads at once.
+///
+/// Each element can be claimed by only one [`Claim`] at a time, which
+/// releases it again when dropped. Which elements are claimed is tracked in
+/// an atomic bitmap, so claiming needs no lock.
+///
+/// # Example
+/// ```
+/// use index_many::borrow_set::{AtomicBorrowSet, TakeError};
+///
+/// let mut v = vec![0; 8];
+/// let set = AtomicBorrowSet::new(&mut v);
+/// std::thread::scope(|s| {
+///     for t in 0..4 {
+///         let set = &set;
+///         s.spawn(move || {
+///             for i in 0..8 {
+///                 // Retry until no other thread holds the pair.
+///                 loop {
+///                     match set.claim_many([i, (i + t + 1) % 8]) {
+///                         Ok([mut a, mut b]) => {
+///                             *a += 1;
+///                             *b += 1;
+///                             break;
+///                         }
+///                         Err(TakeError::AlreadyTaken) => continue,
+///                         Err(err) => panic!("invalid pair: {:?}", err),
+///                     }
+///                 }
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(v.iter().sum::<i32>(), 64);
+/// ```
+pub struct AtomicBorrowSet<'a, T> {
+    slice: *mut [T],
+    claimed: Box<[AtomicU64]>,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+// SAFETY: The set has exclusive access to the slice, and hands out each
+// element to at most one thread at a time.
+unsafe impl<T: Send> Send for AtomicBorrowSet<'_, T> {}
+unsafe impl<T: Send> Sync for AtomicBorrowSet<'_, T> {}
+
+/// An element claimed from an [`AtomicBorrowSet`], which is released again
+/// when this is dropped.
+pub struct Claim<'s, T> {
+    // Not a `&mut T`, since that would have to stay valid during `drop()`,
+    // after the element has already been released to other threads.
+    value: NonNull<T>,
+    word: &'s AtomicU64,
+    mask: u64,
+    _marker: PhantomData<&'s mut T>,
+}
+
+// SAFETY: A claim is an exclusive reference to the element.
+unsafe impl<T: Send> Send for Claim<'_, T> {}
+unsafe impl<T: Sync> Sync for Claim<'_, T> {}
+
+impl<T> Deref for Claim<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The element is claimed until `self` is dropped.
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T> DerefMut for Claim<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The element is claimed until `self` is dropped.
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Claim<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T> Drop for Claim<'_, T> {
+    fn drop(&mut self) {
+        self.word.fetch_and(!self.mask, Ordering::Release);
+    }
+}
+
+impl<'a, T> AtomicBorrowSet<'a, T> {
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            claimed: (0..slice.len().div_ceil(WORD_BITS))
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+            slice,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the element at `idx` is currently claimed.
+    ///
+    /// With other threads around, the result may be outdated by the time it
+    /// is returned.
+    ///
+    /// # Panics
+    /// Panics if `idx` is out of bounds.
+    pub fn is_claimed(&self, idx: usize) -> bool {
+        assert!(idx < self.len(), "index out of bounds");
+        let (word, mask) = self.bit(idx);
+        word.load(Ordering::Relaxed) & mask != 0
+    }
+
+    #[inline]
+    fn bit(&self, idx: usize) -> (&AtomicU64, u64) {
+        (&self.claimed[idx / WORD_BITS], 1 << (idx % WORD_BITS))
+    }
+
+    /// Claims the in-bounds element at `idx`.
+    fn claim_in_bounds(&self, idx: usize) -> Result<Claim<'_, T>, TakeError> {
+        let (word, mask) = self.bit(idx);
+        if word.fetch_or(mask, Ordering::Acquire) & mask != 0 {
+            return Err(TakeError::AlreadyTaken);
+        }
+
+        // SAFETY: The index is in bounds, and the bit we just set ensures
+        // that no other claim for the element exists until ours is dropped.
+        let value = unsafe { NonNull::new_unchecked((self.slice as *mut T).add(idx)) };
+        Ok(Claim {
+            value,
+            word,
+            mask,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Claims the element at `idx`, if it is in bounds and not currently
+    /// claimed.
+    pub fn claim(&self, idx: usize) -> Result<Claim<'_, T>, TakeError> {
+        if idx >= self.len() {
+            return Err(TakeError::OutOfBounds);
+        }
+        self.claim_in_bounds(idx)
+    }
+
+    /// Claims the elements at `indices`, if they are unique, in bounds and
+    /// none of them are currently claimed. The claims are returned in the
+    /// order of `indices`.
+    ///
+    /// The indices are checked before anything is claimed, so only
+    /// [`TakeError::AlreadyTaken`] can go away when retrying. The elements are
+    /// then claimed in ascending index order. If any of them is already
+    /// claimed, the elements claimed so far are released again in the same
+    /// order.
+    pub fn claim_many<const N: usize>(
+        &self,
+        indices: [usize; N],
+    ) -> Result<[Claim<'_, T>; N], TakeError> {
+        if !crate::check_indices_in_bounds(&indices, self.len()) {
+            return Err(TakeError::OutOfBounds);
+        }
+
+        let mut order = [0; N];
+        for (i, pos) in order.iter_mut().enumerate() {
+            *pos = i;
+        }
+        order.sort_unstable_by_key(|&pos| indices[pos]);
+        for &[a, b] in order.array_windows() {
+            if indices[a] == indices[b] {
+                return Err(TakeError::Duplicate);
+            }
+        }
+
+        let mut claims: [Option<Claim<'_, T>>; N] = [(); N].map(|_| None);
+        for (i, &pos) in order.iter().enumerate() {
+            match self.claim_in_bounds(indices[pos]) {
+                Ok(claim) => claims[pos] = Some(claim),
+                Err(err) => {
+                    for &pos in &order[..i] {
+                        drop(claims[pos].take());
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        Ok(claims.map(|claim| claim.expect("every element has been claimed")))
+    }
+
+    pub fn into_inner(self) -> &'a mut [T] {
+        // SAFETY: The set had exclusive access to the slice for `'a`, and all
+        // claims are gone since `self` is consumed.
+        unsafe { &mut *self.slice }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AtomicBorrowSet, BorrowSet, TakeError};
+    use std::thread;
+
+    #[test]
+    fn test_take() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let set = BorrowSet::new(&mut v);
+        let a = set.take(4).unwrap();
+        let b = set.take(0).unwrap();
+        assert_eq!(set.take(4), Err(TakeError::AlreadyTaken));
+        assert_eq!(set.take(5), Err(TakeError::OutOfBounds));
+        assert!(set.is_taken(0));
+        assert!(!set.is_taken(1));
+        *a += 10;
+        *b += 100;
+        assert_eq!(v, vec![101, 2, 3, 4, 15]);
+    }
+
+    #[test]
+    fn test_take_range() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let set = BorrowSet::new(&mut v);
+        let a = set.take_range(1..3).unwrap();
+        assert_eq!(set.take_range(2..4), Err(TakeError::AlreadyTaken));
+        assert_eq!(set.take(1), Err(TakeError::AlreadyTaken));
+        assert_eq!(set.take_range(4..6), Err(TakeError::OutOfBounds));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 4..3;
+        assert_eq!(set.take_range(reversed), Err(TakeError::OutOfBounds));
+        assert!(!set.is_taken(3));
+        let b = set.take_range(3..5).unwrap();
+        let c = set.take_range(5..5).unwrap();
+        assert!(c.is_empty());
+        a.swap_with_slice(&mut b[..2]);
+        assert_eq!(v, vec![1, 4, 5, 2, 3]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut v = vec![1, 2, 3];
+        let mut set = BorrowSet::new(&mut v);
+        for i in 0..3 {
+            let a = set.take(i).unwrap();
+            let b = set.take((i + 1) % 3).unwrap();
+            *b += *a;
+            set.reset();
+        }
+        assert_eq!(set.into_inner(), &[7, 3, 6][..]);
+    }
+
+    #[test]
+    fn test_heap_bitmap() {
+        let mut v: Vec<usize> = (0..1000).collect();
+        let mut set = BorrowSet::new(&mut v);
+        assert_eq!(set.len(), 1000);
+        let a = set.take(999).unwrap();
+        let b = set.take_range(60..200).unwrap();
+        assert_eq!(set.take(130), Err(TakeError::AlreadyTaken));
+        assert_eq!(set.take(1000), Err(TakeError::OutOfBounds));
+        *a += b.iter().sum::<usize>();
+        set.reset();
+        assert!((0..1000).all(|i| !set.is_taken(i)));
+        assert_eq!(v[999], 999 + (60..200).sum::<usize>());
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut v: Vec<i32> = vec![];
+        let set = BorrowSet::new(&mut v);
+        assert!(set.is_empty());
+        assert_eq!(set.take(0), Err(TakeError::OutOfBounds));
+        assert!(set.take_range(0..0).is_ok());
+    }
+
+    #[test]
+    fn test_atomic_claim() {
+        let mut v = vec![1, 2, 3];
+        let set = AtomicBorrowSet::new(&mut v);
+        let mut a = set.claim(1).unwrap();
+        assert_eq!(set.claim(1).unwrap_err(), TakeError::AlreadyTaken);
+        assert_eq!(set.claim(3).unwrap_err(), TakeError::OutOfBounds);
+        assert!(set.is_claimed(1));
+        *a += 10;
+        drop(a);
+        assert!(!set.is_claimed(1));
+        let mut a = set.claim(1).unwrap();
+        *a += 10;
+        drop(a);
+        assert_eq!(set.into_inner(), &[1, 22, 3][..]);
+    }
+
+    #[test]
+    fn test_atomic_claim_many() {
+        let mut v: Vec<i32> = (0..200).collect();
+        let set = AtomicBorrowSet::new(&mut v);
+        let [a, b, c] = set.claim_many([150, 3, 70]).unwrap();
+        assert_eq!((*a, *b, *c), (150, 3, 70));
+
+        assert_eq!(
+            set.claim_many([0, 1, 70]).unwrap_err(),
+            TakeError::AlreadyTaken
+        );
+        assert_eq!(
+            set.claim_many([0, 200]).unwrap_err(),
+            TakeError::OutOfBounds
+        );
+        assert_eq!(set.claim_many([5, 5]).unwrap_err(), TakeError::Duplicate);
+        assert_eq!(
+            set.claim_many([70, 2, 70]).unwrap_err(),
+            TakeError::Duplicate
+        );
+        // Nothing stays claimed after a failed attempt.
+        assert!(!set.is_claimed(0));
+        assert!(!set.is_claimed(1));
+        assert!(!set.is_claimed(5));
+        assert!(set.is_claimed(70));
+
+        drop(c);
+        let [mut d, mut e] = set.claim_many([70, 0]).unwrap();
+        std::mem::swap(&mut *d, &mut *e);
+        drop((a, b, d, e));
+        let v = set.into_inner();
+        assert_eq!((v[0], v[70]), (70, 0));
+    }
+
+    #[test]
+    fn test_atomic_stress() {
+        const LEN: usize = 16;
+        const THREADS: usize = 4;
+        const ROUNDS: usize = 200;
+
+        let mut v = vec![0usize; LEN];
+        let set = AtomicBorrowSet::new(&mut v);
+        let successes: usize = thread::scope(|s| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|t| {
+                    let set = &set;
+                    s.spawn(move || {
+                        let mut successes = 0;
+                        let mut state = t * 7 + 1;
+                        for _ in 0..ROUNDS {
+                            state = (state * 13 + 5) % LEN;
+                            let indices = [state, (state + 1) % LEN, (state + 5) % LEN];
+                            if let Ok(claims) = set.claim_many(indices) {
+                                for mut claim in claims {
+                                    // Not atomic: a race would lose updates.
+                                    let value = *claim;
+                                    thread::yield_now();
+                                    *claim = value + 1;
+                                }
+                                successes += 1;
+                            }
+                        }
+                        successes
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).sum()
+        });
+
+        let v = set.into_inner();
+        assert_eq!(v.iter().sum::<usize>(), successes * 3);
+    }
+
+    #[test]
+    fn test_atomic_stress_single() {
+        const THREADS: usize = 4;
+        const ROUNDS: usize = 100;
+
+        let mut v = vec![0usize; 2];
+        let set = AtomicBorrowSet::new(&mut v);
+        thread::scope(|s| {
+            for t in 0..THREADS {
+                let set = &set;
+                s.spawn(move || {
+                    for _ in 0..ROUNDS {
+                        loop {
+                            if let Ok(mut claim) = set.claim(t % 2) {
+                                let value = *claim;
+                                thread::yield_now();
+                                *claim = value + 1;
+                                break;
+                            }
+                        }
+                    }
+                });
+            }
+        });
+        assert_eq!(set.into_inner(), &[200, 200][..]);
+    }
+}
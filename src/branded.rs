@@ -0,0 +1,222 @@
+//! A variant of the API that validates indices once, and then reuses them
+//! without any further checks.
+//!
+//! [`with_brand()`] gives the slice a unique, invariant lifetime `'id` for the
+//! duration of a closure. Indices validated against the resulting
+//! [`BrandedSlice`] become [`BrandedIndices`] tokens that carry the same
+//! brand, so the type system ensures they can only be used with that slice.
+//! Since the length of the slice can not change while it is borrowed, the
+//! tokens stay valid, and indexing with them needs no comparisons at all.
+//!
+//! # Example
+//! ```
+//! use index_many::branded::with_brand;
+//! use index_many::generic::UnsortedIndices;
+//!
+//! let mut v = vec![1, 2, 3, 4, 5];
+//! with_brand(&mut v, |mut s| {
+//!     let indices = s.validate(UnsortedIndices([4, 0])).unwrap();
+//!     for _ in 0..10 {
+//!         let [a, b] = s.get_many_mut(indices);
+//!         *a += *b;
+//!     }
+//! });
+//! assert_eq!(v, vec![1, 2, 3, 4, 15]);
+//! ```
+//!
+//! Tokens of one slice can not be used with another:
+//! ```compile_fail
+//! use index_many::branded::with_brand;
+//!
+//! let mut v = vec![1, 2, 3];
+//! let mut w = vec![1];
+//! with_brand(&mut v, |s| {
+//!     let indices = s.validate([0, 2]).unwrap();
+//!     with_brand(&mut w, |t| {
+//!         t.get_many(indices);
+//!     });
+//! });
+//! ```
+
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::generic::Indices;
+
+/// An invariant lifetime, that uniquely identifies a single [`with_brand()`] call.
+#[derive(Copy, Clone)]
+struct Brand<'id>(PhantomData<fn(&'id ()) -> &'id ()>);
+
+/// A slice that carries the brand `'id`.
+pub struct BrandedSlice<'id, 'a, T> {
+    slice: &'a mut [T],
+    _brand: Brand<'id>,
+}
+
+/// Indices that have been validated against the [`BrandedSlice`] with the brand `'id`.
+#[derive(Copy, Clone)]
+pub struct BrandedIndices<'id, const N: usize> {
+    indices: [usize; N],
+    _brand: Brand<'id>,
+}
+
+impl<'id, const N: usize> Deref for BrandedIndices<'id, N> {
+    type Target = [usize; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.indices
+    }
+}
+
+/// Calls `f` with a [`BrandedSlice`] of `slice`, that has a brand unique
+/// to this call.
+pub fn with_brand<T, R, F>(slice: &mut [T], f: F) -> R
+where
+    F: for<'id> FnOnce(BrandedSlice<'id, '_, T>) -> R,
+{
+    f(BrandedSlice {
+        slice,
+        _brand: Brand(PhantomData),
+    })
+}
+
+impl<'id, 'a, T> BrandedSlice<'id, 'a, T> {
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slice.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.slice
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.slice
+    }
+
+    /// Validates `indices` against this slice, and returns them as a token
+    /// that can be used with it without further checks.
+    pub fn validate<I: Indices<N>, const N: usize>(
+        &self,
+        indices: I,
+    ) -> Option<BrandedIndices<'id, N>> {
+        if !indices.is_valid(self.slice.len()) {
+            return None;
+        }
+        Some(BrandedIndices {
+            indices: indices.to_raw_indices(),
+            _brand: self._brand,
+        })
+    }
+
+    /// Like [`BrandedSlice::validate()`], but panics if the indices are invalid.
+    pub fn index<I: Indices<N>, const N: usize>(&self, indices: I) -> BrandedIndices<'id, N> {
+        match self.validate(indices) {
+            Some(s) => s,
+            None => {
+                let tmp = indices;
+                tmp.cause_invalid_panic(self.slice.len())
+            }
+        }
+    }
+
+    pub fn get_many<const N: usize>(&self, indices: BrandedIndices<'id, N>) -> [&T; N] {
+        // SAFETY: `indices` has been validated against a slice with the same
+        // brand, which can only be this one, and whose length can not
+        // have changed since.
+        unsafe { crate::get_many_internal(self.slice, indices.indices) }
+    }
+
+    pub fn get_many_mut<const N: usize>(&mut self, indices: BrandedIndices<'id, N>) -> [&mut T; N] {
+        // SAFETY: `indices` has been validated against a slice with the same
+        // brand, which can only be this one, and whose length can not
+        // have changed since.
+        unsafe { crate::get_many_internal_mut(self.slice, indices.indices) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generic::{PresortedIndices, UnsortedIndices};
+
+    #[test]
+    fn test_mut_normal() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        with_brand(&mut v, |mut s| {
+            let indices = s.index([0, 2, 4]);
+            let [a, b, c] = s.get_many_mut(indices);
+            *a += 10;
+            *b += 100;
+            *c += 1000;
+        });
+        assert_eq!(v, vec![11, 2, 103, 4, 1005]);
+    }
+
+    #[test]
+    fn test_ref_normal() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let sum = with_brand(&mut v, |s| {
+            let indices = s.index(UnsortedIndices([4, 1]));
+            assert_eq!(*indices, [4, 1]);
+            let [a, b] = s.get_many(indices);
+            a + b
+        });
+        assert_eq!(sum, 7);
+    }
+
+    #[test]
+    fn test_reuse() {
+        let mut v = vec![0; 6];
+        with_brand(&mut v, |mut s| {
+            let evens = s.index(PresortedIndices::new([0, 2, 4]).unwrap());
+            let odds = s.index([1, 3, 5]);
+            for i in 0..3 {
+                for x in s.get_many_mut(evens) {
+                    *x += i;
+                }
+                for x in s.get_many_mut(odds) {
+                    *x += 1;
+                }
+            }
+        });
+        assert_eq!(v, vec![3, 3, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut v: Vec<i32> = vec![];
+        with_brand(&mut v, |mut s| {
+            assert!(s.is_empty());
+            let indices = s.index([]);
+            let [] = s.get_many_mut(indices);
+            assert!(s.validate([0]).is_none());
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        with_brand(&mut v, |s| {
+            assert_eq!(s.len(), 5);
+            assert!(s.validate([0, 5]).is_none());
+            assert!(s.validate([3, 1]).is_none());
+            assert!(s.validate(UnsortedIndices([1, 1])).is_none());
+        });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 3 appears more than once (indices [1, 3, 3, 4], position 1 and 2)"
+    )]
+    fn test_duplicate() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        with_brand(&mut v, |s| {
+            s.index([1, 3, 3, 4]);
+        });
+    }
+}
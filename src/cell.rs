@@ -0,0 +1,391 @@
+//! A variant of the API that hands out shared `&Cell<T>` references.
+//!
+//! Since cells can alias, the indices only need to be in bounds. They can
+//! be given in any order, and may contain duplicates. Checking them needs
+//! a single comparison of the largest index against the slice length.
+//!
+//! # Example
+//! ```
+//! use index_many::cell::get_many_cells;
+//!
+//! let mut v = vec![1, 2, 3, 4, 5];
+//! let [a, b, c] = get_many_cells(&mut v, [4, 0, 4]).unwrap();
+//! a.set(a.get() + b.get());
+//! c.set(c.get() * 10);
+//! assert_eq!(v, vec![1, 2, 3, 4, 60]);
+//! ```
+//!
+//! It also contains functions for borrowing many elements of a slice of
+//! `RefCell`s at once, like [`try_borrow_many_mut()`]. These either borrow
+//! all requested cells or none of them.
+//!
+//! # Example codegen
+//! See [`crate::_doc_assembly::option_cells()`]
+
+use std::cell::{Cell, Ref, RefCell, RefMut};
+use std::ops::Range;
+
+use crate::generic::Indices;
+
+#[inline]
+fn check_range_indices_in_bounds<const N: usize>(indices: &[Range<usize>; N], len: usize) -> bool {
+    let mut valid = true;
+
+    for idx in indices {
+        valid &= idx.start <= idx.end;
+        valid &= idx.end <= len;
+    }
+
+    valid
+}
+
+/// # Safety
+/// All indices have to be in bounds of `slice`.
+pub unsafe fn index_many_cells_unchecked<T, const N: usize>(
+    slice: &mut [T],
+    indices: [usize; N],
+) -> [&Cell<T>; N] {
+    let cells = Cell::from_mut(slice).as_slice_of_cells();
+    crate::get_many_internal(cells, indices)
+}
+
+pub fn get_many_cells<T, const N: usize>(
+    slice: &mut [T],
+    indices: [usize; N],
+) -> Option<[&Cell<T>; N]> {
+    if !crate::check_indices_in_bounds(&indices, slice.len()) {
+        return None;
+    }
+    // SAFETY: We checked that all indices are in bounds, and cells
+    // are allowed to alias.
+    unsafe { Some(index_many_cells_unchecked(slice, indices)) }
+}
+
+pub fn index_many_cells<T, const N: usize>(slice: &mut [T], indices: [usize; N]) -> [&Cell<T>; N] {
+    let len = slice.len();
+    match get_many_cells(slice, indices) {
+        Some(s) => s,
+        None => {
+            let tmp = indices;
+            crate::bound_check_failed(&tmp, len)
+        }
+    }
+}
+
+/// # Safety
+/// All ranges have to be in bounds of `slice`.
+pub unsafe fn index_many_cell_ranges_unchecked<T, const N: usize>(
+    slice: &mut [T],
+    indices: [Range<usize>; N],
+) -> [&[Cell<T>]; N] {
+    let cells = Cell::from_mut(slice).as_slice_of_cells();
+    crate::get_many_internal(cells, indices)
+}
+
+pub fn get_many_cell_ranges<T, const N: usize>(
+    slice: &mut [T],
+    indices: [Range<usize>; N],
+) -> Option<[&[Cell<T>]; N]> {
+    if !check_range_indices_in_bounds(&indices, slice.len()) {
+        return None;
+    }
+    // SAFETY: We checked that all ranges are in bounds, and cells
+    // are allowed to alias.
+    unsafe { Some(index_many_cell_ranges_unchecked(slice, indices)) }
+}
+
+pub fn index_many_cell_ranges<T, const N: usize>(
+    slice: &mut [T],
+    indices: [Range<usize>; N],
+) -> [&[Cell<T>]; N] {
+    let len = slice.len();
+    if !check_range_indices_in_bounds(&indices, len) {
+        crate::slice_index::overlapping_range_check_fail(&indices, len)
+    }
+    // SAFETY: We checked that all ranges are in bounds, and cells
+    // are allowed to alias.
+    unsafe { index_many_cell_ranges_unchecked(slice, indices) }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BorrowManyError {
+    /// The indices are not valid for the slice.
+    InvalidIndices,
+    /// The cell at `slice_idx` is already borrowed in a conflicting way.
+    AlreadyBorrowed { slice_idx: usize },
+}
+
+/// Mutably borrows the cells at `indices`.
+///
+/// The indices are checked like for [`crate::generic::get_many_mut()`]. If
+/// any of the cells is already borrowed, the cells borrowed so far are
+/// released again, and no cell stays borrowed.
+///
+/// # Example
+/// ```
+/// use std::cell::RefCell;
+/// use index_many::cell::{try_borrow_many_mut, BorrowManyError};
+/// use index_many::generic::UnsortedIndices;
+///
+/// let nodes = vec![RefCell::new(1), RefCell::new(2), RefCell::new(3)];
+/// let [mut a, mut b] = try_borrow_many_mut(&nodes, UnsortedIndices([2, 0])).unwrap();
+/// std::mem::swap(&mut *a, &mut *b);
+///
+/// assert_eq!(
+///     try_borrow_many_mut(&nodes, [0, 1]).unwrap_err(),
+///     BorrowManyError::AlreadyBorrowed { slice_idx: 0 },
+/// );
+/// drop((a, b));
+/// assert_eq!(*nodes[0].borrow(), 3);
+/// ```
+pub fn try_borrow_many_mut<T, I: Indices<N>, const N: usize>(
+    cells: &[RefCell<T>],
+    indices: I,
+) -> Result<[RefMut<'_, T>; N], BorrowManyError> {
+    if !indices.is_valid(cells.len()) {
+        return Err(BorrowManyError::InvalidIndices);
+    }
+    borrow_all(cells, indices.to_raw_indices(), RefCell::try_borrow_mut)
+}
+
+/// Borrows the cells at `shared` immutably, and the cells at `exclusive`
+/// mutably.
+///
+/// The indices of `shared` only need to be in bounds, and may repeat. The
+/// indices of `exclusive` are checked like for
+/// [`crate::generic::get_many_mut()`], and must not appear in `shared`. If
+/// any of the cells is already borrowed in a conflicting way, the cells
+/// borrowed so far are released again, and no cell stays borrowed.
+///
+/// # Example
+/// ```
+/// use std::cell::RefCell;
+/// use index_many::cell::try_borrow_many_mixed;
+///
+/// let nodes = vec![RefCell::new(1), RefCell::new(2), RefCell::new(3)];
+/// let ([a, b], [mut c]) = try_borrow_many_mixed(&nodes, [0, 1], [2]).unwrap();
+/// *c += *a + *b;
+/// drop(c);
+/// assert_eq!(*nodes[2].borrow(), 6);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn try_borrow_many_mixed<T, I: Indices<W>, const R: usize, const W: usize>(
+    cells: &[RefCell<T>],
+    shared: [usize; R],
+    exclusive: I,
+) -> Result<([Ref<'_, T>; R], [RefMut<'_, T>; W]), BorrowManyError> {
+    let exclusive = if exclusive.is_valid(cells.len()) {
+        exclusive.to_raw_indices()
+    } else {
+        return Err(BorrowManyError::InvalidIndices);
+    };
+
+    let mut valid = crate::check_indices_in_bounds(&shared, cells.len());
+    for &idx in &shared {
+        for &idx2 in &exclusive {
+            valid &= idx != idx2;
+        }
+    }
+    if !valid {
+        return Err(BorrowManyError::InvalidIndices);
+    }
+
+    let shared = borrow_all(cells, shared, RefCell::try_borrow)?;
+    let exclusive = borrow_all(cells, exclusive, RefCell::try_borrow_mut)?;
+    Ok((shared, exclusive))
+}
+
+/// Borrows the cells at the in-bounds `indices` with `borrow`, releasing the
+/// cells borrowed so far if any of them fails.
+fn borrow_all<'a, T, G, E, F, const N: usize>(
+    cells: &'a [RefCell<T>],
+    indices: [usize; N],
+    mut borrow: F,
+) -> Result<[G; N], BorrowManyError>
+where
+    F: FnMut(&'a RefCell<T>) -> Result<G, E>,
+{
+    let mut guards: [Option<G>; N] = [(); N].map(|_| None);
+    for (guard, &slice_idx) in guards.iter_mut().zip(&indices) {
+        match borrow(&cells[slice_idx]) {
+            Ok(g) => *guard = Some(g),
+            Err(_) => return Err(BorrowManyError::AlreadyBorrowed { slice_idx }),
+        }
+    }
+    Ok(guards.map(|guard| guard.expect("every cell has been borrowed")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generic::UnsortedIndices;
+
+    #[test]
+    fn test_normal() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let [a, b, c] = index_many_cells(&mut v, [0, 2, 4]);
+        a.set(a.get() + 10);
+        b.set(b.get() + 100);
+        c.set(c.get() + 1000);
+        assert_eq!(v, vec![11, 2, 103, 4, 1005]);
+    }
+
+    #[test]
+    fn test_unsorted() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let [a, b, c] = index_many_cells(&mut v, [3, 1, 4]);
+        a.swap(b);
+        c.set(0);
+        assert_eq!(v, vec![1, 4, 3, 2, 0]);
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let [a, b] = index_many_cells(&mut v, [1, 1]);
+        a.set(10);
+        assert_eq!(b.get(), 10);
+        assert!(std::ptr::eq(a, b));
+        assert_eq!(v, vec![1, 10, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut v: Vec<i32> = vec![];
+        let [] = index_many_cells(&mut v, []);
+        assert!(get_many_cells(&mut v, []).is_some());
+    }
+
+    #[test]
+    fn test_oob() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        assert!(get_many_cells(&mut v, [0, 5, 1]).is_none());
+        assert!(get_many_cells(&mut v, [usize::MAX]).is_none());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 5 is out of bounds of slice with len 5 (indices [1, 1, 5], position 2)"
+    )]
+    fn test_oob_panic() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        index_many_cells(&mut v, [1, 1, 5]);
+    }
+
+    #[test]
+    fn test_ranges_overlapping() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let [a, b] = index_many_cell_ranges(&mut v, [3..5, 0..4]);
+        for (x, y) in a.iter().zip(b) {
+            y.set(y.get() + x.get());
+        }
+        assert_eq!(v, vec![5, 7, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_ranges_oob() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        assert!(get_many_cell_ranges(&mut v, [0..2, 4..6]).is_none());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = [0..1, 3..2];
+        assert!(get_many_cell_ranges(&mut v, reversed).is_none());
+        assert!(get_many_cell_ranges(&mut v, [5..5, 0..5]).is_some());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Range 4..6 is out of bounds of slice with len 5 (ranges [0..2, 4..6], at 1)"
+    )]
+    fn test_ranges_oob_panic() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        index_many_cell_ranges(&mut v, [0..2, 4..6]);
+    }
+
+    #[test]
+    fn test_borrow_many_mut() {
+        let cells: Vec<_> = (0..5).map(RefCell::new).collect();
+        let [mut a, mut b, c] = try_borrow_many_mut(&cells, [0, 2, 4]).unwrap();
+        *a += 10;
+        *b += 20;
+        assert_eq!(*c, 4);
+        drop((a, b, c));
+        assert_eq!(
+            cells.iter().map(|c| *c.borrow()).collect::<Vec<_>>(),
+            vec![10, 1, 22, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_borrow_many_mut_all_or_nothing() {
+        let cells: Vec<_> = (0..5).map(RefCell::new).collect();
+        let held = cells[3].borrow();
+        assert_eq!(
+            try_borrow_many_mut(&cells, UnsortedIndices([0, 1, 3, 2])).unwrap_err(),
+            BorrowManyError::AlreadyBorrowed { slice_idx: 3 }
+        );
+        for cell in &cells[..3] {
+            assert!(cell.try_borrow_mut().is_ok());
+        }
+        drop(held);
+        assert!(try_borrow_many_mut(&cells, UnsortedIndices([0, 1, 3, 2])).is_ok());
+    }
+
+    #[test]
+    fn test_borrow_many_mut_invalid() {
+        let cells: Vec<_> = (0..3).map(RefCell::new).collect();
+        assert_eq!(
+            try_borrow_many_mut(&cells, UnsortedIndices([1, 1])).unwrap_err(),
+            BorrowManyError::InvalidIndices
+        );
+        assert_eq!(
+            try_borrow_many_mut(&cells, [1, 3]).unwrap_err(),
+            BorrowManyError::InvalidIndices
+        );
+        assert!(cells.iter().all(|c| c.try_borrow_mut().is_ok()));
+    }
+
+    #[test]
+    fn test_borrow_many_mixed() {
+        let cells: Vec<_> = (0..4).map(RefCell::new).collect();
+        let ([a, b, c], [mut d, mut e]) =
+            try_borrow_many_mixed(&cells, [1, 1, 3], UnsortedIndices([2, 0])).unwrap();
+        *d += *a + *b + *c;
+        *e += 100;
+        drop((a, b, c, d, e));
+        assert_eq!(*cells[2].borrow(), 7);
+        assert_eq!(*cells[0].borrow(), 100);
+    }
+
+    #[test]
+    fn test_borrow_many_mixed_invalid() {
+        let cells: Vec<_> = (0..4).map(RefCell::new).collect();
+        assert_eq!(
+            try_borrow_many_mixed(&cells, [1, 2], [2]).unwrap_err(),
+            BorrowManyError::InvalidIndices
+        );
+        assert_eq!(
+            try_borrow_many_mixed(&cells, [4], [2]).unwrap_err(),
+            BorrowManyError::InvalidIndices
+        );
+
+        let held = cells[3].borrow_mut();
+        assert_eq!(
+            try_borrow_many_mixed(&cells, [0, 3], [1]).unwrap_err(),
+            BorrowManyError::AlreadyBorrowed { slice_idx: 3 }
+        );
+        assert_eq!(
+            try_borrow_many_mixed(&cells, [0], [1, 3]).unwrap_err(),
+            BorrowManyError::AlreadyBorrowed { slice_idx: 3 }
+        );
+        drop(held);
+
+        let held = cells[1].borrow();
+        assert!(try_borrow_many_mixed(&cells, [1], [0]).is_ok());
+        assert_eq!(
+            try_borrow_many_mixed(&cells, [0], [1]).unwrap_err(),
+            BorrowManyError::AlreadyBorrowed { slice_idx: 1 }
+        );
+        drop(held);
+        assert!(cells.iter().all(|c| c.try_borrow_mut().is_ok()));
+    }
+}
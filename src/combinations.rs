@@ -0,0 +1,247 @@
+//! Functions for visiting every pair, or every combination of `K` distinct
+//! elements of a slice mutably.
+//!
+//! The visited indices are generated in strictly increasing order, so they
+//! are unique and in bounds by construction, and no combination needs to be
+//! validated.
+//!
+//! # Example
+//! ```
+//! use index_many::combinations::for_each_pair_mut;
+//!
+//! // Every element gets the sum of all others
+//! let mut v = vec![(1, 0), (2, 0), (3, 0)];
+//! for_each_pair_mut(&mut v, |[a, b]| {
+//!     a.1 += b.0;
+//!     b.1 += a.0;
+//! });
+//! assert_eq!(v, vec![(1, 5), (2, 4), (3, 3)]);
+//! ```
+
+use crate::generic::{Indices, PresortedIndices};
+
+/// Calls `f` with every combination of `K` distinct indices below `n`, in
+/// lexicographic order. Each combination is sorted.
+#[inline]
+fn for_each_index_combination<F, const K: usize>(n: usize, mut f: F)
+where
+    F: FnMut([usize; K]),
+{
+    if K > n {
+        return;
+    }
+
+    let mut comb = [0; K];
+    for (i, c) in comb.iter_mut().enumerate() {
+        *c = i;
+    }
+
+    loop {
+        f(comb);
+
+        // Find the rightmost index that can still be advanced.
+        let mut i = K;
+        loop {
+            if i == 0 {
+                return;
+            }
+            i -= 1;
+            if comb[i] < n - K + i {
+                break;
+            }
+        }
+
+        comb[i] += 1;
+        for j in i + 1..K {
+            comb[j] = comb[j - 1] + 1;
+        }
+    }
+}
+
+/// Calls `f` with every unordered pair of distinct elements, as
+/// `[&mut slice[i], &mut slice[j]]` with `i < j`.
+///
+/// Pairs are visited in lexicographic order of `(i, j)`.
+pub fn for_each_pair_mut<T, F>(slice: &mut [T], mut f: F)
+where
+    F: FnMut([&mut T; 2]),
+{
+    let len = slice.len();
+    let slice: *mut [T] = slice;
+    for i in 0..len {
+        for j in i + 1..len {
+            // SAFETY: `i < j < len`.
+            unsafe { f(crate::get_many_internal_mut(slice, [i, j])) };
+        }
+    }
+}
+
+/// Calls `f` with every combination of `K` distinct elements, in increasing
+/// index order within each combination.
+///
+/// Combinations are visited in lexicographic order of their indices. If
+/// `K == 0`, `f` is called once with an empty array.
+///
+/// # Example
+/// ```
+/// use index_many::combinations::for_each_combination_mut;
+///
+/// let mut v = vec![0; 4];
+/// let mut count = 0;
+/// for_each_combination_mut(&mut v, |[a, b, c]| {
+///     *a += 1;
+///     *b += 1;
+///     *c += 1;
+///     count += 1;
+/// });
+/// assert_eq!(count, 4);
+/// assert_eq!(v, vec![3, 3, 3, 3]);
+/// ```
+pub fn for_each_combination_mut<T, F, const K: usize>(slice: &mut [T], mut f: F)
+where
+    F: FnMut([&mut T; K]),
+{
+    let len = slice.len();
+    let slice: *mut [T] = slice;
+    for_each_index_combination(len, |comb| {
+        // SAFETY: The indices are strictly increasing and below `len`.
+        unsafe { f(crate::get_many_internal_mut(slice, comb)) }
+    });
+}
+
+/// Calls `f` with every unordered pair of distinct elements whose indices
+/// are in `subset`, as `[&mut slice[i], &mut slice[j]]` with `i < j`.
+///
+/// `subset` is checked against the slice once, not per pair.
+///
+/// # Panics
+/// Panics if any index of `subset` is out of bounds.
+///
+/// # Example
+/// ```
+/// use index_many::combinations::for_each_pair_mut_in;
+/// use index_many::generic::PresortedIndices;
+///
+/// let mut v = vec![1, 2, 3, 4, 5];
+/// let subset = PresortedIndices::new([0, 2, 4]).unwrap();
+/// let mut pairs = vec![];
+/// for_each_pair_mut_in(&mut v, subset, |[a, b]| pairs.push((*a, *b)));
+/// assert_eq!(pairs, vec![(1, 3), (1, 5), (3, 5)]);
+/// ```
+pub fn for_each_pair_mut_in<T, F, const N: usize>(
+    slice: &mut [T],
+    subset: PresortedIndices<N>,
+    mut f: F,
+) where
+    F: FnMut([&mut T; 2]),
+{
+    if !subset.is_valid(slice.len()) {
+        subset.cause_invalid_panic(slice.len());
+    }
+
+    let slice: *mut [T] = slice;
+    for i in 0..N {
+        for j in i + 1..N {
+            // SAFETY: `subset` is sorted, unique and in bounds, and `i < j`.
+            unsafe { f(crate::get_many_internal_mut(slice, [subset[i], subset[j]])) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs() {
+        let mut v = vec![1, 2, 3, 4];
+        let mut pairs = vec![];
+        for_each_pair_mut(&mut v, |[a, b]| {
+            pairs.push((*a, *b));
+            *b += 10;
+        });
+        assert_eq!(
+            pairs,
+            vec![(1, 2), (1, 3), (1, 4), (12, 13), (12, 14), (23, 24)]
+        );
+        assert_eq!(v, vec![1, 12, 23, 34]);
+    }
+
+    #[test]
+    fn test_pairs_short() {
+        for_each_pair_mut(&mut [1], |[_, _]: [&mut i32; 2]| unreachable!());
+        for_each_pair_mut(&mut [], |[_, _]: [&mut i32; 2]| unreachable!());
+    }
+
+    #[test]
+    fn test_combinations() {
+        let mut v = vec![0, 1, 2, 3, 4];
+        let mut combs = vec![];
+        for_each_combination_mut(&mut v, |[a, b, c]| combs.push([*a, *b, *c]));
+        assert_eq!(
+            combs,
+            vec![
+                [0, 1, 2],
+                [0, 1, 3],
+                [0, 1, 4],
+                [0, 2, 3],
+                [0, 2, 4],
+                [0, 3, 4],
+                [1, 2, 3],
+                [1, 2, 4],
+                [1, 3, 4],
+                [2, 3, 4],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_combinations_matches_pairs() {
+        let mut v: Vec<i32> = (0..6).collect();
+        let mut pairs = vec![];
+        for_each_pair_mut(&mut v, |[a, b]| pairs.push((*a, *b)));
+        let mut combs = vec![];
+        for_each_combination_mut(&mut v, |[a, b]| combs.push((*a, *b)));
+        assert_eq!(pairs, combs);
+        assert_eq!(pairs.len(), 15);
+    }
+
+    #[test]
+    fn test_combinations_edge() {
+        let mut v = vec![1, 2, 3];
+        let mut calls = 0;
+        for_each_combination_mut(&mut v, |[]: [&mut i32; 0]| calls += 1);
+        assert_eq!(calls, 1);
+
+        for_each_combination_mut(&mut v, |[a, b, c]| {
+            std::mem::swap(a, c);
+            *b = 0;
+            calls += 1;
+        });
+        assert_eq!(calls, 2);
+        assert_eq!(v, vec![3, 0, 1]);
+
+        for_each_combination_mut(&mut v, |[_, _, _, _]: [&mut i32; 4]| unreachable!());
+    }
+
+    #[test]
+    fn test_pairs_in_subset() {
+        let mut v = vec![0; 6];
+        let subset = PresortedIndices::new([1, 3, 4]).unwrap();
+        for_each_pair_mut_in(&mut v, subset, |[a, b]| {
+            *a += 1;
+            *b += 10;
+        });
+        assert_eq!(v, vec![0, 2, 0, 11, 20, 0]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 6 is out of bounds of slice with len 6 (indices [1, 6], position 1)"
+    )]
+    fn test_pairs_in_subset_oob() {
+        let mut v = vec![0; 6];
+        let subset = PresortedIndices::new([1, 6]).unwrap();
+        for_each_pair_mut_in(&mut v, subset, |[_, _]| {});
+    }
+}
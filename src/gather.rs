@@ -0,0 +1,398 @@
+//! Functions for reading and writing many `Copy` elements by value.
+//!
+//! Gathering only reads the elements, so its indices merely have to be in
+//! bounds and may repeat. Scattering validates its indices the same way as
+//! [`crate::generic::get_many_mut()`], so they also have to be unique.
+//!
+//! # Vector instructions
+//! For the primitive integer and float types of 4 or 8 bytes, like `u32`,
+//! `f64` or `usize`, the accesses are done in blocks of four with explicit
+//! vector instructions, if the target features are enabled at compile time,
+//! e.g. with `-C target-cpu=x86-64-v3`:
+//! - Gathering uses `vpgatherqd`/`vpgatherqq`, which need `avx2`.
+//! - Scattering uses `vpscatterqd`/`vpscatterqq`, which need `avx512f` and
+//!   `avx512vl`, e.g. with `-C target-cpu=x86-64-v4`. AVX2 has no scatter
+//!   instruction.
+//!
+//! Any remaining elements, elements of all other types, and all elements on
+//! other targets, are accessed one at a time in a loop without branches.
+//!
+//! # Example
+//! ```
+//! use index_many::gather::{gather, scatter};
+//! use index_many::generic::UnsortedIndices;
+//!
+//! let mut v = vec![1, 2, 3, 4, 5];
+//! let [a, b, c, d] = gather(&v, [4, 2, 0, 2]);
+//! scatter(&mut v, UnsortedIndices([4, 2, 0]), [a * 10, b * 10, c * 10 + d]);
+//! assert_eq!(v, vec![13, 2, 30, 4, 50]);
+//! ```
+//!
+//! # Example codegen
+//! See [`crate::_doc_assembly::checked_gather()`], which is compiled with
+//! `-C target-cpu=x86-64-v3`, and [`crate::_doc_assembly::checked_scatter()`],
+//! which is compiled with `-C target-cpu=x86-64-v4`.
+
+use crate::generic::Indices;
+
+/// Copies the elements at `indices` one at a time.
+///
+/// # Safety
+/// The indices have to be in bounds of the slice starting at `ptr`.
+#[inline(always)]
+unsafe fn gather_scalar<T: Copy, const N: usize>(ptr: *const T, indices: &[usize; N]) -> [T; N] {
+    indices.map(|idx| *ptr.add(idx))
+}
+
+/// Writes `values` to the elements at `indices` one at a time, in order.
+///
+/// # Safety
+/// The indices have to be in bounds of the slice starting at `ptr`.
+#[inline(always)]
+unsafe fn scatter_scalar<T: Copy, const N: usize>(
+    ptr: *mut T,
+    indices: &[usize; N],
+    values: &[T; N],
+) {
+    for (&idx, &value) in indices.iter().zip(values) {
+        *ptr.add(idx) = value;
+    }
+}
+
+/// How the elements of a type are copied by [`gather_unchecked()`] and
+/// [`scatter_unchecked()`].
+///
+/// All `Copy` types use the scalar loops, except for the primitive types of
+/// [`simd::Primitive`], if the target supports vector instructions for them.
+trait Access: Copy {
+    /// # Safety
+    /// The indices have to be in bounds of the slice starting at `ptr`.
+    unsafe fn gather<const N: usize>(ptr: *const Self, indices: &[usize; N]) -> [Self; N];
+
+    /// # Safety
+    /// The indices have to be in bounds of the slice starting at `ptr`.
+    unsafe fn scatter<const N: usize>(ptr: *mut Self, indices: &[usize; N], values: &[Self; N]);
+}
+
+impl<T: Copy> Access for T {
+    #[inline(always)]
+    default unsafe fn gather<const N: usize>(ptr: *const T, indices: &[usize; N]) -> [T; N] {
+        gather_scalar(ptr, indices)
+    }
+
+    #[inline(always)]
+    default unsafe fn scatter<const N: usize>(ptr: *mut T, indices: &[usize; N], values: &[T; N]) {
+        scatter_scalar(ptr, indices, values)
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+impl<T: simd::Primitive> Access for T {
+    #[inline(always)]
+    unsafe fn gather<const N: usize>(ptr: *const T, indices: &[usize; N]) -> [T; N] {
+        simd::gather(ptr, indices)
+    }
+
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
+    #[inline(always)]
+    unsafe fn scatter<const N: usize>(ptr: *mut T, indices: &[usize; N], values: &[T; N]) {
+        simd::scatter(ptr, indices, values)
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+mod simd {
+    use std::arch::x86_64::*;
+    use std::mem::{self, MaybeUninit};
+
+    const LANES: usize = 4;
+
+    /// Primitive integer and float types of 4 or 8 bytes.
+    ///
+    /// Their values are plain bits, without padding, uninitialized bytes or
+    /// provenance, so they can be copied through integer vectors. Other
+    /// `Copy` types of the same size, like references or `(u16, u8)`, can
+    /// not, which is why this is not implemented outside of this module.
+    #[rustc_specialization_trait]
+    pub(super) trait Primitive: Copy {}
+
+    macro_rules! impl_primitive {
+        ($($ty:ty)*) => {
+            $(impl Primitive for $ty {})*
+        };
+    }
+
+    impl_primitive!(u32 i32 f32 u64 i64 f64 usize isize);
+
+    /// Reads the elements at `indices` in blocks of `LANES`, and the rest
+    /// one at a time.
+    ///
+    /// # Safety
+    /// The indices have to be in bounds of the slice starting at `ptr`.
+    #[inline(always)]
+    pub(super) unsafe fn gather<T: Primitive, const N: usize>(
+        ptr: *const T,
+        indices: &[usize; N],
+    ) -> [T; N] {
+        let size = mem::size_of::<T>();
+        if N < LANES || (size != 4 && size != 8) {
+            return super::gather_scalar(ptr, indices);
+        }
+
+        let mut out = MaybeUninit::<[T; N]>::uninit();
+        let out_ptr = out.as_mut_ptr().cast::<T>();
+
+        let mut i = 0;
+        while i + LANES <= N {
+            let offsets = _mm256_loadu_si256(indices.as_ptr().add(i).cast());
+            if size == 8 {
+                let values = _mm256_i64gather_epi64::<8>(ptr.cast(), offsets);
+                _mm256_storeu_si256(out_ptr.add(i).cast(), values);
+            } else {
+                let values = _mm256_i64gather_epi32::<4>(ptr.cast(), offsets);
+                _mm_storeu_si128(out_ptr.add(i).cast(), values);
+            }
+            i += LANES;
+        }
+        for (j, &idx) in indices.iter().enumerate().skip(i) {
+            out_ptr.add(j).write(*ptr.add(idx));
+        }
+
+        out.assume_init()
+    }
+
+    /// Writes `values` to the elements at `indices` in blocks of `LANES`,
+    /// and the rest one at a time.
+    ///
+    /// Like the scalar loop, this writes the values in order, so the last
+    /// value written to a repeated index wins.
+    ///
+    /// # Safety
+    /// The indices have to be in bounds of the slice starting at `ptr`.
+    #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
+    #[inline(always)]
+    pub(super) unsafe fn scatter<T: Primitive, const N: usize>(
+        ptr: *mut T,
+        indices: &[usize; N],
+        values: &[T; N],
+    ) {
+        let size = mem::size_of::<T>();
+        if N < LANES || (size != 4 && size != 8) {
+            return super::scatter_scalar(ptr, indices, values);
+        }
+
+        let values_ptr = values.as_ptr();
+
+        let mut i = 0;
+        while i + LANES <= N {
+            let offsets = _mm256_loadu_si256(indices.as_ptr().add(i).cast());
+            if size == 8 {
+                let block = _mm256_loadu_si256(values_ptr.add(i).cast());
+                _mm256_i64scatter_epi64::<8>(ptr.cast(), offsets, block);
+            } else {
+                let block = _mm_loadu_si128(values_ptr.add(i).cast());
+                _mm256_i64scatter_epi32::<4>(ptr.cast(), offsets, block);
+            }
+            i += LANES;
+        }
+        for (&idx, &value) in indices.iter().zip(values).skip(i) {
+            *ptr.add(idx) = value;
+        }
+    }
+}
+
+/// # Safety
+/// The indices have to be in bounds of `slice`.
+#[inline]
+pub unsafe fn gather_unchecked<T: Copy, const N: usize>(
+    slice: &[T],
+    indices: [usize; N],
+) -> [T; N] {
+    Access::gather(slice.as_ptr(), &indices)
+}
+
+/// # Safety
+/// The indices have to be in bounds of `slice`. If they are not unique, the
+/// last value written to an index wins.
+#[inline]
+pub unsafe fn scatter_unchecked<T: Copy, I: Indices<N>, const N: usize>(
+    slice: &mut [T],
+    indices: I,
+    values: [T; N],
+) {
+    Access::scatter(slice.as_mut_ptr(), &indices.to_raw_indices(), &values)
+}
+
+/// Returns copies of the elements at `indices`, if all of them are in bounds
+/// of `slice`.
+///
+/// The indices may be in any order, and may repeat.
+#[inline]
+pub fn try_gather<T: Copy, const N: usize>(slice: &[T], indices: [usize; N]) -> Option<[T; N]> {
+    if !crate::check_indices_in_bounds(&indices, slice.len()) {
+        return None;
+    }
+    // SAFETY: The indices have been checked.
+    unsafe { Some(gather_unchecked(slice, indices)) }
+}
+
+/// Writes `values` to the elements at `indices`, if the indices are valid
+/// for `slice`. Otherwise, returns the values back.
+#[inline]
+pub fn try_scatter<T: Copy, I: Indices<N>, const N: usize>(
+    slice: &mut [T],
+    indices: I,
+    values: [T; N],
+) -> Result<(), [T; N]> {
+    if !indices.is_valid(slice.len()) {
+        return Err(values);
+    }
+    // SAFETY: The indices have been checked.
+    unsafe { scatter_unchecked(slice, indices, values) };
+    Ok(())
+}
+
+/// Returns copies of the elements at `indices`.
+///
+/// # Panics
+/// Panics if any of the indices is out of bounds of `slice`.
+#[inline]
+pub fn gather<T: Copy, const N: usize>(slice: &[T], indices: [usize; N]) -> [T; N] {
+    match try_gather(slice, indices) {
+        Some(values) => values,
+        None => crate::bound_check_failed(&indices, slice.len()),
+    }
+}
+
+/// Writes `values` to the elements at `indices`.
+///
+/// # Panics
+/// Panics if the indices are not valid for `slice`.
+#[inline]
+pub fn scatter<T: Copy, I: Indices<N>, const N: usize>(
+    slice: &mut [T],
+    indices: I,
+    values: [T; N],
+) {
+    let len = slice.len();
+    if try_scatter(slice, indices, values).is_err() {
+        indices.cause_invalid_panic(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generic::UnsortedIndices;
+    use std::mem::MaybeUninit;
+
+    #[test]
+    fn test_gather() {
+        let v = vec![1, 2, 3, 4, 5];
+        assert_eq!(gather(&v, [1, 3]), [2, 4]);
+        assert_eq!(gather(&v, [4, 0, 2]), [5, 1, 3]);
+        assert_eq!(gather(&v, [3, 3]), [4, 4]);
+        assert_eq!(gather(&v, []), [0; 0]);
+    }
+
+    #[test]
+    fn test_gather_blocks() {
+        let v32: Vec<u32> = (0..10).map(|i| i * 10).collect();
+        let v64: Vec<u64> = (0..10).map(|i| i * 10).collect();
+        let indices = [9, 0, 3, 3, 7, 1, 8, 2, 5];
+        assert_eq!(gather(&v32, indices), [90, 0, 30, 30, 70, 10, 80, 20, 50]);
+        assert_eq!(gather(&v64, indices), [90, 0, 30, 30, 70, 10, 80, 20, 50]);
+
+        let floats: Vec<f32> = (0..10).map(|i| i as f32 / 2.0).collect();
+        assert_eq!(gather(&floats, [9, 0, 3, 3, 7]), [4.5, 0.0, 1.5, 1.5, 3.5]);
+    }
+
+    #[test]
+    fn test_gather_non_primitive() {
+        // These have the size of a primitive, but must not be copied through
+        // integer vectors, because of provenance, padding or uninit bytes.
+        let values: Vec<i32> = (0..10).collect();
+        let refs: Vec<&i32> = values.iter().collect();
+        let [a, b, c, d] = gather(&refs, [4, 2, 0, 9]);
+        assert_eq!((*a, *b, *c, *d), (4, 2, 0, 9));
+
+        let padded: Vec<(u32, u16)> = (0..10).map(|i| (i, i as u16 + 1)).collect();
+        assert_eq!(
+            gather(&padded, [4, 2, 0, 9]),
+            [(4, 5), (2, 3), (0, 1), (9, 10)]
+        );
+
+        let mut uninit = [MaybeUninit::<u64>::uninit(); 10];
+        uninit[3].write(30);
+        let [x, _, _, y] = gather(&uninit, [3, 0, 1, 3]);
+        assert_eq!(unsafe { (x.assume_init(), y.assume_init()) }, (30, 30));
+
+        let mut slots = vec![MaybeUninit::<u64>::uninit(); 10];
+        scatter(
+            &mut slots,
+            UnsortedIndices([1, 5, 0, 8]),
+            [x, uninit[0], y, uninit[1]],
+        );
+        assert_eq!(
+            unsafe { (slots[0].assume_init(), slots[1].assume_init()) },
+            (30, 30)
+        );
+    }
+
+    #[test]
+    fn test_scatter() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        scatter(&mut v, [0, 4], [10, 50]);
+        scatter(&mut v, UnsortedIndices([3, 1]), [40, 20]);
+        assert_eq!(v, vec![10, 20, 3, 40, 50]);
+    }
+
+    #[test]
+    fn test_scatter_blocks() {
+        let indices = UnsortedIndices([9, 0, 3, 7, 1, 8]);
+        let mut v32 = vec![0u32; 10];
+        let mut v64 = vec![0u64; 10];
+        scatter(&mut v32, indices, [1, 2, 3, 4, 5, 6]);
+        scatter(&mut v64, indices, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(v32, vec![2, 5, 0, 3, 0, 0, 0, 4, 6, 1]);
+        assert_eq!(v64, vec![2, 5, 0, 3, 0, 0, 0, 4, 6, 1]);
+    }
+
+    #[test]
+    fn test_scatter_unchecked_repeated() {
+        let mut v = vec![0u64; 4];
+        unsafe { scatter_unchecked(&mut v, UnsortedIndices([1, 1, 2, 1, 1]), [1, 2, 3, 4, 5]) };
+        assert_eq!(v, vec![0, 5, 3, 0]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut v = vec![1, 2, 3];
+        assert_eq!(try_gather(&v, [1, 3]), None);
+        assert_eq!(try_gather(&v, [1, 1]), Some([2, 2]));
+        assert_eq!(try_scatter(&mut v, [0, 3], [7, 8]), Err([7, 8]));
+        assert_eq!(
+            try_scatter(&mut v, UnsortedIndices([2, 2]), [7, 8]),
+            Err([7, 8])
+        );
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 3 is out of bounds of slice with len 3 (indices [2, 3, 2], position 1)"
+    )]
+    fn test_gather_oob() {
+        let v = vec![1, 2, 3];
+        gather(&v, [2, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 2 appears more than once (indices [2, 0, 2], position 0 and 2)"
+    )]
+    fn test_scatter_duplicate() {
+        let mut v = vec![1, 2, 3];
+        scatter(&mut v, UnsortedIndices([2, 0, 2]), [1, 2, 3]);
+    }
+}
@@ -7,14 +7,23 @@
 //! - `UnsortedIndices<N>` allows any order for the indices.
 //! - `UnsortedSpecializedIndices<N>` allows any order for the indices,
 //!    but tries to specialize for `N <= 3`.
+//! - `Strided<N>` describes evenly spaced indices without storing them.
+//! - `IndicesFor<I>` wraps any of the above, after validating them
+//!    against a fixed slice length.
 
+mod indices_for;
 mod presorted_indices;
 mod sorted_indices;
+mod strided;
 mod unsorted_indices;
 mod unsorted_specialized_indices;
 
+pub use indices_for::IndicesFor;
+pub use indices_for::IndicesForError;
 pub use presorted_indices::PresortedIndices;
 pub use presorted_indices::PresortedIndicesError;
+pub use strided::Strided;
+pub use strided::StridedChunks;
 pub use unsorted_indices::UnsortedIndices;
 pub use unsorted_specialized_indices::UnsortedSpecializedIndices;
 
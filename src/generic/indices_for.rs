@@ -0,0 +1,210 @@
+use std::ops::Range;
+
+use super::Indices;
+
+/// This type records the slice length that the wrapped indices have been
+/// validated against.
+///
+/// Checking the indices against a slice then needs only a single comparison
+/// of the slice length against the recorded one. Unlike
+/// [`crate::branded::BrandedIndices`], this is not tied to a specific
+/// slice, so it can be reused with any slice of the same length.
+///
+/// This works on top of any `I: Indices<N>`, as well as on top of
+/// `[Range<usize>; N]` via [`IndicesFor::new_ranges()`].
+///
+/// # Example codegen
+/// See [`crate::_doc_assembly::checked_indices_for()`]
+#[derive(Copy, Clone)]
+pub struct IndicesFor<I> {
+    indices: I,
+    len: usize,
+}
+
+#[derive(Debug)]
+pub struct IndicesForError {
+    _private: (),
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn len_mismatch_fail(expected: usize, len: usize) -> ! {
+    panic!(
+        "Indices were validated for a slice with len {}, but used with a slice with len {}",
+        expected, len
+    );
+}
+
+impl<I> IndicesFor<I> {
+    /// Validates `indices` for slices with length `len`.
+    pub fn new<const N: usize>(indices: I, len: usize) -> Result<Self, IndicesForError>
+    where
+        I: Indices<N>,
+    {
+        if indices.is_valid(len) {
+            Ok(Self { indices, len })
+        } else {
+            Err(IndicesForError { _private: () })
+        }
+    }
+
+    /// The slice length the indices have been validated against.
+    pub fn slice_len(&self) -> usize {
+        self.len
+    }
+
+    pub fn indices(&self) -> &I {
+        &self.indices
+    }
+
+    pub fn into_inner(self) -> I {
+        self.indices
+    }
+}
+
+unsafe impl<I: Indices<N>, const N: usize> Indices<N> for IndicesFor<I> {
+    #[inline]
+    fn to_raw_indices(&self) -> [usize; N] {
+        self.indices.to_raw_indices()
+    }
+
+    #[inline]
+    fn is_valid(&self, len: usize) -> bool {
+        len == self.len
+    }
+
+    #[inline(always)]
+    fn cause_invalid_panic(&self, len: usize) -> ! {
+        len_mismatch_fail(self.len, len)
+    }
+}
+
+impl<const N: usize> IndicesFor<[Range<usize>; N]> {
+    /// Validates the ranges `indices` for slices with length `len`.
+    ///
+    /// The ranges have to be sorted and must not overlap, like for
+    /// [`crate::slice_index::get_many()`].
+    pub fn new_ranges(indices: [Range<usize>; N], len: usize) -> Result<Self, IndicesForError> {
+        if crate::slice_index::check_range_indices_valid(&indices, len) {
+            Ok(Self { indices, len })
+        } else {
+            Err(IndicesForError { _private: () })
+        }
+    }
+
+    pub fn get_many<'a, T>(&self, slice: &'a [T]) -> Option<[&'a [T]; N]> {
+        if slice.len() != self.len {
+            return None;
+        }
+        // SAFETY: The ranges are valid for slices of this length.
+        unsafe { Some(crate::get_many_internal(slice, self.indices.clone())) }
+    }
+
+    pub fn get_many_mut<'a, T>(&self, slice: &'a mut [T]) -> Option<[&'a mut [T]; N]> {
+        if slice.len() != self.len {
+            return None;
+        }
+        // SAFETY: The ranges are valid for slices of this length.
+        unsafe { Some(crate::get_many_internal_mut(slice, self.indices.clone())) }
+    }
+
+    pub fn index_many<'a, T>(&self, slice: &'a [T]) -> [&'a [T]; N] {
+        let len = slice.len();
+        match self.get_many(slice) {
+            Some(s) => s,
+            None => len_mismatch_fail(self.len, len),
+        }
+    }
+
+    pub fn index_many_mut<'a, T>(&self, slice: &'a mut [T]) -> [&'a mut [T]; N] {
+        let len = slice.len();
+        match self.get_many_mut(slice) {
+            Some(s) => s,
+            None => len_mismatch_fail(self.len, len),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{index_many, index_many_mut, PresortedIndices, UnsortedIndices};
+    use super::IndicesFor;
+
+    #[test]
+    fn test_mut_normal() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let indices = IndicesFor::new([0, 2, 4], v.len()).unwrap();
+        let [a, b, c] = index_many_mut(&mut v, indices);
+        *a += 10;
+        *b += 100;
+        *c += 1000;
+        assert_eq!(v, vec![11, 2, 103, 4, 1005]);
+    }
+
+    #[test]
+    fn test_ref_normal() {
+        let v = vec![1, 2, 3, 4, 5];
+        let indices = IndicesFor::new(UnsortedIndices([4, 0]), v.len()).unwrap();
+        assert_eq!(indices.slice_len(), 5);
+        let [a, b] = index_many(&v, indices);
+        assert_eq!(a, &5);
+        assert_eq!(b, &1);
+    }
+
+    #[test]
+    fn test_reuse_across_slices() {
+        let indices = IndicesFor::new(PresortedIndices::new([1, 2]).unwrap(), 3).unwrap();
+        let mut v = vec![1, 2, 3];
+        let mut w = vec![4, 5, 6];
+        for s in [&mut v, &mut w] {
+            let [a, b] = index_many_mut(s, indices);
+            std::mem::swap(a, b);
+        }
+        assert_eq!(v, vec![1, 3, 2]);
+        assert_eq!(w, vec![4, 6, 5]);
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        assert!(IndicesFor::new([0, 3], 3).is_err());
+        assert!(IndicesFor::new([2, 1], 3).is_err());
+        assert!(IndicesFor::new(UnsortedIndices([1, 1]), 3).is_err());
+        assert!(IndicesFor::new(UnsortedIndices([2, 1]), 3).is_ok());
+    }
+
+    #[test]
+    fn test_len_mismatch() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let indices = IndicesFor::new([0, 1], 4).unwrap();
+        assert!(super::super::get_many_mut(&mut v, indices).is_none());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Indices were validated for a slice with len 3, but used with a slice with len 5"
+    )]
+    fn test_len_mismatch_panic() {
+        let v = vec![1, 2, 3, 4, 5];
+        let indices = IndicesFor::new([0, 1], 3).unwrap();
+        index_many(&v, indices);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let indices = IndicesFor::new_ranges([0..2, 3..5], v.len()).unwrap();
+        let [a, b] = indices.index_many_mut(&mut v);
+        a.swap_with_slice(b);
+        assert_eq!(v, vec![4, 5, 3, 1, 2]);
+        assert_eq!(indices.index_many(&v), [&[4, 5][..], &[1, 2][..]]);
+        assert!(indices.get_many(&v[1..]).is_none());
+    }
+
+    #[test]
+    fn test_ranges_invalid() {
+        assert!(IndicesFor::new_ranges([0..2, 1..3], 5).is_err());
+        assert!(IndicesFor::new_ranges([2..4, 0..1], 5).is_err());
+        assert!(IndicesFor::new_ranges([0..2, 4..6], 5).is_err());
+    }
+}
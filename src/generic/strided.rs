@@ -0,0 +1,354 @@
+use std::ops::Range;
+
+use super::Indices;
+
+/// The indices `[start, start + step, ..., start + (N - 1) * step]`.
+///
+/// A `step` of at least one ensures that the indices are sorted and unique,
+/// so checking them needs only a single overflow-checked computation of
+/// the last index, and a single comparison against the slice length.
+///
+/// # Example
+/// ```
+/// use index_many::generic::{index_many_mut, Strided};
+///
+/// // The second column of a 3x3 matrix
+/// let mut m = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+/// let [a, b, c] = index_many_mut(&mut m, Strided { start: 1, step: 3 });
+/// std::mem::swap(a, c);
+/// *b = 0;
+/// assert_eq!(m, vec![0, 7, 2, 3, 0, 5, 6, 1, 8]);
+/// ```
+///
+/// # Example codegen
+/// See [`crate::_doc_assembly::checked_strided()`]
+#[derive(Copy, Clone, Debug)]
+pub struct Strided<const N: usize> {
+    pub start: usize,
+    pub step: usize,
+}
+
+/// `N` chunks of length `chunk_len`, that start at the indices of [`Strided`].
+///
+/// A `chunk_len` of at most `step` ensures that the chunks don't overlap.
+#[derive(Copy, Clone, Debug)]
+pub struct StridedChunks<const N: usize> {
+    pub start: usize,
+    pub step: usize,
+    pub chunk_len: usize,
+}
+
+impl<const N: usize> Strided<N> {
+    /// Returns the last index, or `None` if `N == 0` or it overflows.
+    #[inline]
+    fn last(&self) -> Option<usize> {
+        let n = N.checked_sub(1)?;
+        self.step.checked_mul(n)?.checked_add(self.start)
+    }
+
+    /// Turns these indices into chunks of length `chunk_len` starting at them.
+    pub fn chunks(self, chunk_len: usize) -> StridedChunks<N> {
+        StridedChunks {
+            start: self.start,
+            step: self.step,
+            chunk_len,
+        }
+    }
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn strided_check_fail(start: usize, step: usize, n: usize, chunk_len: usize, len: usize) -> ! {
+    if n > 1 && step < chunk_len {
+        panic!(
+            "Strided indices with step {} overlap for chunk len {} (start {}, count {})",
+            step, chunk_len, start, n
+        );
+    }
+    // NB: The last index itself can be in range of `usize` even if the end
+    // of its chunk is not, which still means it is out of bounds.
+    let last = match step
+        .checked_mul(n - 1)
+        .and_then(|offset| offset.checked_add(start))
+    {
+        Some(last) => last,
+        None => panic!(
+            "Strided indices from {} with step {} overflow (count {})",
+            start, step, n
+        ),
+    };
+    match last.checked_add(chunk_len) {
+        Some(end) => panic!(
+            "Strided indices from {} with step {} end at {}, which is out of bounds of slice with len {} (count {})",
+            start, step, end, len, n
+        ),
+        None => panic!(
+            "Strided indices from {} with step {} reach index {}, which is out of bounds of slice with len {} (count {})",
+            start, step, last, len, n
+        ),
+    }
+}
+
+unsafe impl<const N: usize> Indices<N> for Strided<N> {
+    #[inline]
+    fn to_raw_indices(&self) -> [usize; N] {
+        let mut indices = [0; N];
+        let mut idx = self.start;
+        for i in &mut indices {
+            *i = idx;
+            idx = idx.wrapping_add(self.step);
+        }
+        indices
+    }
+
+    #[inline]
+    fn is_valid(&self, len: usize) -> bool {
+        if N == 0 {
+            return true;
+        }
+
+        let mut valid = N == 1 || self.step != 0;
+
+        match self.last() {
+            Some(last) => valid &= last < len,
+            None => valid = false,
+        }
+
+        valid
+    }
+
+    #[inline(always)]
+    fn cause_invalid_panic(&self, len: usize) -> ! {
+        strided_check_fail(self.start, self.step, N, 1, len)
+    }
+}
+
+impl<const N: usize> StridedChunks<N> {
+    #[inline]
+    fn is_valid(&self, len: usize) -> bool {
+        if N == 0 {
+            return true;
+        }
+
+        let mut valid = N == 1 || self.step >= self.chunk_len;
+
+        let strided = Strided::<N> {
+            start: self.start,
+            step: self.step,
+        };
+        match strided
+            .last()
+            .and_then(|last| last.checked_add(self.chunk_len))
+        {
+            Some(end) => valid &= end <= len,
+            None => valid = false,
+        }
+
+        valid
+    }
+
+    #[inline]
+    fn raw_ranges(&self) -> [Range<usize>; N] {
+        let strided = Strided::<N> {
+            start: self.start,
+            step: self.step,
+        };
+        strided
+            .to_raw_indices()
+            .map(|idx| idx..idx.wrapping_add(self.chunk_len))
+    }
+
+    #[inline(never)]
+    #[cold]
+    #[track_caller]
+    fn cause_invalid_panic(&self, len: usize) -> ! {
+        strided_check_fail(self.start, self.step, N, self.chunk_len, len)
+    }
+
+    /// # Safety
+    /// The chunks have to be in bounds of `slice`, and must not overlap.
+    pub unsafe fn index_many_unchecked<'a, T>(&self, slice: &'a [T]) -> [&'a [T]; N] {
+        crate::get_many_internal(slice, self.raw_ranges())
+    }
+
+    /// # Safety
+    /// The chunks have to be in bounds of `slice`, and must not overlap.
+    pub unsafe fn index_many_mut_unchecked<'a, T>(&self, slice: &'a mut [T]) -> [&'a mut [T]; N] {
+        crate::get_many_internal_mut(slice, self.raw_ranges())
+    }
+
+    pub fn get_many<'a, T>(&self, slice: &'a [T]) -> Option<[&'a [T]; N]> {
+        if !self.is_valid(slice.len()) {
+            return None;
+        }
+        unsafe { Some(self.index_many_unchecked(slice)) }
+    }
+
+    pub fn get_many_mut<'a, T>(&self, slice: &'a mut [T]) -> Option<[&'a mut [T]; N]> {
+        if !self.is_valid(slice.len()) {
+            return None;
+        }
+        unsafe { Some(self.index_many_mut_unchecked(slice)) }
+    }
+
+    pub fn index_many<'a, T>(&self, slice: &'a [T]) -> [&'a [T]; N] {
+        let len = slice.len();
+        match self.get_many(slice) {
+            Some(s) => s,
+            None => self.cause_invalid_panic(len),
+        }
+    }
+
+    pub fn index_many_mut<'a, T>(&self, slice: &'a mut [T]) -> [&'a mut [T]; N] {
+        let len = slice.len();
+        match self.get_many_mut(slice) {
+            Some(s) => s,
+            None => self.cause_invalid_panic(len),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{get_many, index_many, index_many_mut};
+    use super::{Strided, StridedChunks};
+
+    #[test]
+    fn test_mut_normal() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let [a, b, c] = index_many_mut(&mut v, Strided { start: 0, step: 2 });
+        *a += 10;
+        *b += 100;
+        *c += 1000;
+        assert_eq!(v, vec![11, 2, 103, 4, 1005]);
+    }
+
+    #[test]
+    fn test_ref_normal() {
+        let v = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let [a, b, c] = index_many(&v, Strided { start: 1, step: 3 });
+        assert_eq!((a, b, c), (&2, &5, &8));
+    }
+
+    #[test]
+    fn test_ref_empty() {
+        let v: Vec<i32> = vec![];
+        let [] = index_many(&v, Strided { start: 7, step: 0 });
+    }
+
+    #[test]
+    fn test_ref_single() {
+        let v = vec![1, 2, 3];
+        let [a] = index_many(&v, Strided { start: 2, step: 0 });
+        assert_eq!(a, &3);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let v = vec![1, 2, 3, 4, 5];
+        assert!(get_many(&v, Strided::<3> { start: 0, step: 0 }).is_none());
+        assert!(get_many(&v, Strided::<3> { start: 1, step: 2 }).is_none());
+        assert!(get_many(&v, Strided::<3> { start: 0, step: 2 }).is_some());
+        assert!(get_many(&v, Strided::<1> { start: 5, step: 1 }).is_none());
+        assert!(get_many(
+            &v,
+            Strided::<3> {
+                start: 1,
+                step: usize::MAX / 2
+            }
+        )
+        .is_none());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Strided indices from 1 with step 2 end at 6, which is out of bounds of slice with len 5 (count 3)"
+    )]
+    fn test_oob() {
+        let v = vec![1, 2, 3, 4, 5];
+        index_many(&v, Strided::<3> { start: 1, step: 2 });
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Strided indices from 1 with step 9223372036854775807 reach index 18446744073709551615, which is out of bounds of slice with len 5 (count 3)"
+    )]
+    fn test_last_index_max() {
+        let v = vec![1, 2, 3, 4, 5];
+        index_many(
+            &v,
+            Strided::<3> {
+                start: 1,
+                step: isize::MAX as usize,
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Strided indices from 2 with step 9223372036854775807 overflow (count 3)"
+    )]
+    fn test_overflow() {
+        let v = vec![1, 2, 3, 4, 5];
+        index_many(
+            &v,
+            Strided::<3> {
+                start: 2,
+                step: isize::MAX as usize,
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Strided indices with step 0 overlap for chunk len 1 (start 0, count 2)"
+    )]
+    fn test_step_zero() {
+        let v = vec![1, 2, 3, 4, 5];
+        index_many(&v, Strided::<2> { start: 0, step: 0 });
+    }
+
+    #[test]
+    fn test_chunks() {
+        let mut v: Vec<i32> = (0..10).collect();
+        let chunks = Strided::<3> { start: 1, step: 3 }.chunks(2);
+        let [a, b, c] = chunks.index_many_mut(&mut v);
+        assert_eq!((&*a, &*b, &*c), (&[1, 2][..], &[4, 5][..], &[7, 8][..]));
+        a.swap_with_slice(c);
+        assert_eq!(v, vec![0, 7, 8, 3, 4, 5, 6, 1, 2, 9]);
+    }
+
+    #[test]
+    fn test_chunks_invalid() {
+        let v: Vec<i32> = (0..10).collect();
+        let chunks = |start, step, chunk_len| StridedChunks::<3> {
+            start,
+            step,
+            chunk_len,
+        };
+        assert!(chunks(0, 3, 3).get_many(&v).is_some());
+        assert!(chunks(1, 3, 3).get_many(&v).is_some());
+        assert!(chunks(2, 3, 3).get_many(&v).is_none());
+        assert!(chunks(0, 2, 3).get_many(&v).is_none());
+        assert!(chunks(0, 0, 0).get_many(&v).is_some());
+        assert!(StridedChunks::<1> {
+            start: 0,
+            step: 0,
+            chunk_len: 10
+        }
+        .get_many(&v)
+        .is_some());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Strided indices with step 2 overlap for chunk len 3 (start 0, count 3)"
+    )]
+    fn test_chunks_overlap() {
+        let mut v: Vec<i32> = (0..10).collect();
+        Strided::<3> { start: 0, step: 2 }
+            .chunks(3)
+            .index_many_mut(&mut v);
+    }
+}
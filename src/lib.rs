@@ -3,16 +3,37 @@
 #![feature(is_sorted)]
 #![feature(unchecked_math)]
 #![feature(rustc_attrs)]
+#![feature(min_specialization)]
 #![doc = include_str!("../README.md")]
 
 use std::{array::IntoIter, mem::MaybeUninit, slice::SliceIndex};
 
 pub mod _doc_assembly;
+pub mod array;
+pub mod batch;
+pub mod borrow_set;
+pub mod branded;
+pub mod cell;
+pub mod combinations;
+pub mod gather;
 pub mod generic;
+pub mod map;
+pub mod par;
+pub mod pin;
+pub mod ptr;
 pub mod simple;
 pub mod simple_result;
 pub mod slice_index;
 pub mod std_proposal;
+pub mod stencil;
+pub mod sync;
+#[cfg(test)]
+mod test_util;
+pub mod transaction;
+pub mod uninit;
+pub mod vec;
+pub mod vec_deque;
+pub mod windows;
 
 unsafe fn get_many_internal<'a, T, I: SliceIndex<[T]>, const N: usize>(
     slice: *const [T],
@@ -38,6 +59,24 @@ unsafe fn get_many_internal_mut<'a, T, I: SliceIndex<[T]>, const N: usize>(
     arr.assume_init()
 }
 
+unsafe fn take_many_internal<T, const N: usize>(
+    slots: &mut [Option<T>],
+    indices: [usize; N],
+) -> [T; N] {
+    indices.map(|idx| slots.get_unchecked_mut(idx).take().unwrap_unchecked())
+}
+
+/// Checks only that all indices are in bounds, which needs a single comparison
+/// of the maximum index against `len`.
+#[inline]
+fn check_indices_in_bounds(indices: &[usize], len: usize) -> bool {
+    let mut max = 0;
+    for &idx in indices {
+        max = max.max(idx);
+    }
+    indices.is_empty() || max < len
+}
+
 fn bound_check_failed(indices: &[usize], len: usize) -> ! {
     for (i, &idx) in indices.iter().enumerate() {
         if idx >= len {
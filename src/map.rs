@@ -0,0 +1,50 @@
+//! A variant of the API for maps, which selects values by their keys.
+//!
+//! - `btree_*` functions work on a `BTreeMap`, and find all values in a
+//!   single walk over the map.
+//! - [`HashMapExt`] adds methods to a `HashMap`, which look up each key
+//!   separately.
+//!
+//! All variants report missing and duplicate keys with a
+//! [`GetManyKeysError`].
+
+mod btree_map;
+mod hash_map;
+
+pub use btree_map::{btree_get_many_mut, btree_index_many_mut, btree_try_get_many_mut};
+pub use hash_map::HashMapExt;
+
+/// The reason why the values for a list of keys could not be returned.
+///
+/// Positions refer to the list of keys given by the caller.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GetManyKeysError {
+    /// The key at `many_idx` is not in the map.
+    Missing { many_idx: usize },
+    /// The keys at `many_idx_1` and `many_idx_2` are equal.
+    Duplicate {
+        many_idx_1: usize,
+        many_idx_2: usize,
+    },
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn keys_check_fail(err: GetManyKeysError, n: usize) -> ! {
+    match err {
+        GetManyKeysError::Missing { many_idx } => {
+            panic!(
+                "Key at position {} is not in the map (count {})",
+                many_idx, n
+            )
+        }
+        GetManyKeysError::Duplicate {
+            many_idx_1,
+            many_idx_2,
+        } => panic!(
+            "Keys at position {} and {} are equal (count {})",
+            many_idx_1, many_idx_2, n
+        ),
+    }
+}
@@ -0,0 +1,223 @@
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use super::GetManyKeysError;
+
+/// Returns the positions of `keys` in ascending key order, or the first pair
+/// of equal keys.
+fn sorted_order<Q: Ord + ?Sized, const N: usize>(
+    keys: &[&Q; N],
+) -> Result<[usize; N], GetManyKeysError> {
+    let mut order = [0; N];
+    for (i, pos) in order.iter_mut().enumerate() {
+        *pos = i;
+    }
+
+    // Fast path for keys that are already sorted.
+    if !keys.windows(2).all(|w| w[0] < w[1]) {
+        order.sort_by(|&a, &b| keys[a].cmp(keys[b]));
+    }
+
+    for w in order.windows(2) {
+        if keys[w[0]] == keys[w[1]] {
+            return Err(GetManyKeysError::Duplicate {
+                many_idx_1: w[0].min(w[1]),
+                many_idx_2: w[0].max(w[1]),
+            });
+        }
+    }
+    Ok(order)
+}
+
+/// Returns mutable references to the values for `keys`, or why that is not
+/// possible.
+///
+/// The values are found in a single walk over the entries from the smallest
+/// to the largest key, after sorting the keys if they are not sorted
+/// already. The references are returned in the order of `keys`.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use index_many::map::{btree_try_get_many_mut, GetManyKeysError};
+///
+/// let mut map = BTreeMap::from([("a", 1), ("b", 2), ("c", 3)]);
+/// let [c, a] = btree_try_get_many_mut(&mut map, ["c", "a"]).unwrap();
+/// std::mem::swap(c, a);
+/// assert_eq!(map["a"], 3);
+///
+/// assert_eq!(
+///     btree_try_get_many_mut(&mut map, ["a", "d"]).unwrap_err(),
+///     GetManyKeysError::Missing { many_idx: 1 },
+/// );
+/// assert_eq!(
+///     btree_try_get_many_mut(&mut map, ["b", "a", "b"]).unwrap_err(),
+///     GetManyKeysError::Duplicate { many_idx_1: 0, many_idx_2: 2 },
+/// );
+/// ```
+pub fn btree_try_get_many_mut<'a, K, V, Q, const N: usize>(
+    map: &'a mut BTreeMap<K, V>,
+    keys: [&Q; N],
+) -> Result<[&'a mut V; N], GetManyKeysError>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    let order = sorted_order(&keys)?;
+    let start = match order.first() {
+        Some(&pos) => Bound::Included(keys[pos]),
+        None => Bound::Unbounded,
+    };
+
+    let mut values: [Option<&'a mut V>; N] = [(); N].map(|_| None);
+    let mut entries = map.range_mut::<Q, _>((start, Bound::Unbounded)).peekable();
+    for pos in order {
+        while let Some((k, _)) = entries.peek() {
+            if (*k).borrow() >= keys[pos] {
+                break;
+            }
+            entries.next();
+        }
+        match entries.next_if(|(k, _)| (*k).borrow() == keys[pos]) {
+            Some((_, v)) => values[pos] = Some(v),
+            None => return Err(GetManyKeysError::Missing { many_idx: pos }),
+        }
+    }
+
+    Ok(values.map(|v| v.expect("every value has been found")))
+}
+
+/// Returns mutable references to the values for `keys`, if all keys are in
+/// the map and unique.
+///
+/// See [`btree_try_get_many_mut()`].
+pub fn btree_get_many_mut<'a, K, V, Q, const N: usize>(
+    map: &'a mut BTreeMap<K, V>,
+    keys: [&Q; N],
+) -> Option<[&'a mut V; N]>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    btree_try_get_many_mut(map, keys).ok()
+}
+
+/// Returns mutable references to the values for `keys`.
+///
+/// See [`btree_try_get_many_mut()`].
+///
+/// # Panics
+/// Panics if any key is not in the map, or if any two keys are equal.
+pub fn btree_index_many_mut<'a, K, V, Q, const N: usize>(
+    map: &'a mut BTreeMap<K, V>,
+    keys: [&Q; N],
+) -> [&'a mut V; N]
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    match btree_try_get_many_mut(map, keys) {
+        Ok(values) => values,
+        Err(err) => super::keys_check_fail(err, N),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(len: u32) -> BTreeMap<u32, u32> {
+        (0..len).map(|k| (k * 2, k * 20)).collect()
+    }
+
+    #[test]
+    fn test_sorted() {
+        let mut m = map(100);
+        let [a, b, c] = btree_index_many_mut(&mut m, [&0, &50, &198]);
+        assert_eq!((*a, *b, *c), (0, 500, 1980));
+        *a += 1;
+        *b += 1;
+        *c += 1;
+        assert_eq!((m[&0], m[&50], m[&198]), (1, 501, 1981));
+    }
+
+    #[test]
+    fn test_unsorted() {
+        let mut m = map(100);
+        let [a, b, c] = btree_index_many_mut(&mut m, [&120, &4, &60]);
+        assert_eq!((*a, *b, *c), (1200, 40, 600));
+        std::mem::swap(a, b);
+        assert_eq!((m[&4], m[&120]), (1200, 40));
+    }
+
+    #[test]
+    fn test_borrowed_keys() {
+        let mut m: BTreeMap<String, i32> = BTreeMap::new();
+        m.insert("x".into(), 1);
+        m.insert("y".into(), 2);
+        let [y, x] = btree_index_many_mut(&mut m, ["y", "x"]);
+        *x += *y;
+        assert_eq!(m["x"], 3);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut m = map(3);
+        let [] = btree_index_many_mut::<_, _, u32, 0>(&mut m, []);
+        let mut empty: BTreeMap<u32, u32> = BTreeMap::new();
+        assert!(btree_get_many_mut(&mut empty, [&0]).is_none());
+    }
+
+    #[test]
+    fn test_missing() {
+        let mut m = map(10);
+        assert_eq!(
+            btree_try_get_many_mut(&mut m, [&2, &3]).unwrap_err(),
+            GetManyKeysError::Missing { many_idx: 1 }
+        );
+        assert_eq!(
+            btree_try_get_many_mut(&mut m, [&100, &2]).unwrap_err(),
+            GetManyKeysError::Missing { many_idx: 0 }
+        );
+        assert_eq!(
+            btree_try_get_many_mut(&mut m, [&18, &1, &0]).unwrap_err(),
+            GetManyKeysError::Missing { many_idx: 1 }
+        );
+        assert!(btree_get_many_mut(&mut m, [&18, &0]).is_some());
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let mut m = map(10);
+        assert_eq!(
+            btree_try_get_many_mut(&mut m, [&2, &4, &2]).unwrap_err(),
+            GetManyKeysError::Duplicate {
+                many_idx_1: 0,
+                many_idx_2: 2
+            }
+        );
+        // Duplicates are reported before missing keys.
+        assert_eq!(
+            btree_try_get_many_mut(&mut m, [&1, &1]).unwrap_err(),
+            GetManyKeysError::Duplicate {
+                many_idx_1: 0,
+                many_idx_2: 1
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Key at position 2 is not in the map (count 3)")]
+    fn test_missing_panic() {
+        let mut m = map(10);
+        btree_index_many_mut(&mut m, [&2, &4, &5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys at position 0 and 1 are equal (count 2)")]
+    fn test_duplicate_panic() {
+        let mut m = map(10);
+        btree_index_many_mut(&mut m, [&2, &2]);
+    }
+}
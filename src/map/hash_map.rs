@@ -0,0 +1,286 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::ptr;
+
+use super::GetManyKeysError;
+
+/// Checks that no two keys are equal, which requires `O(N^2)` comparisons,
+/// like for [`crate::generic::UnsortedIndices`].
+fn check_keys_unique<Q: Eq + ?Sized, const N: usize>(
+    keys: &[&Q; N],
+) -> Result<(), GetManyKeysError> {
+    for (i, &key) in keys.iter().enumerate() {
+        for (j, &key2) in keys[..i].iter().enumerate() {
+            if key == key2 {
+                return Err(GetManyKeysError::Duplicate {
+                    many_idx_1: j,
+                    many_idx_2: i,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Methods for accessing the values of many keys of a `HashMap` at once.
+///
+/// The keys may be given in any order, but must be unique. Duplicate keys are
+/// reported before missing ones. Keys that are not equal to each other, but
+/// still find the same entry because of an inconsistent `Hash` or `Eq` impl,
+/// are reported as duplicates as well.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use index_many::map::{GetManyKeysError, HashMapExt};
+///
+/// let mut map = HashMap::from([("a", 1), ("b", 2), ("c", 3)]);
+/// let [c, a] = map.index_many_mut(["c", "a"]);
+/// std::mem::swap(c, a);
+/// assert_eq!(map["a"], 3);
+///
+/// assert_eq!(
+///     map.try_get_many_mut(["a", "d"]).unwrap_err(),
+///     GetManyKeysError::Missing { many_idx: 1 },
+/// );
+/// assert!(map.get_many_mut(["b", "b"]).is_none());
+/// ```
+pub trait HashMapExt<K, V> {
+    /// Returns references to the values for `keys`, or why that is not
+    /// possible.
+    fn try_get_many<Q, const N: usize>(&self, keys: [&Q; N]) -> Result<[&V; N], GetManyKeysError>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    /// Returns mutable references to the values for `keys`, or why that is
+    /// not possible.
+    fn try_get_many_mut<Q, const N: usize>(
+        &mut self,
+        keys: [&Q; N],
+    ) -> Result<[&mut V; N], GetManyKeysError>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    /// Returns references to the values for `keys`, if all keys are in the
+    /// map and unique.
+    fn get_many<Q, const N: usize>(&self, keys: [&Q; N]) -> Option<[&V; N]>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.try_get_many(keys).ok()
+    }
+
+    /// Returns mutable references to the values for `keys`, if all keys are
+    /// in the map and unique.
+    fn get_many_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> Option<[&mut V; N]>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.try_get_many_mut(keys).ok()
+    }
+
+    /// Returns references to the values for `keys`.
+    ///
+    /// # Panics
+    /// Panics if any key is not in the map, or if any two keys are equal.
+    #[track_caller]
+    fn index_many<Q, const N: usize>(&self, keys: [&Q; N]) -> [&V; N]
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.try_get_many(keys) {
+            Ok(values) => values,
+            Err(err) => super::keys_check_fail(err, N),
+        }
+    }
+
+    /// Returns mutable references to the values for `keys`.
+    ///
+    /// # Panics
+    /// Panics if any key is not in the map, or if any two keys are equal.
+    #[track_caller]
+    fn index_many_mut<Q, const N: usize>(&mut self, keys: [&Q; N]) -> [&mut V; N]
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.try_get_many_mut(keys) {
+            Ok(values) => values,
+            Err(err) => super::keys_check_fail(err, N),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> HashMapExt<K, V> for HashMap<K, V, S> {
+    fn try_get_many<Q, const N: usize>(&self, keys: [&Q; N]) -> Result<[&V; N], GetManyKeysError>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        check_keys_unique(&keys)?;
+
+        let mut values: [Option<&V>; N] = [None; N];
+        for (many_idx, (value, key)) in values.iter_mut().zip(keys).enumerate() {
+            match self.get(key) {
+                Some(v) => *value = Some(v),
+                None => return Err(GetManyKeysError::Missing { many_idx }),
+            }
+        }
+        Ok(values.map(|v| v.expect("every value has been found")))
+    }
+
+    fn try_get_many_mut<Q, const N: usize>(
+        &mut self,
+        keys: [&Q; N],
+    ) -> Result<[&mut V; N], GetManyKeysError>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        check_keys_unique(&keys)?;
+
+        let mut values: [*mut V; N] = [ptr::null_mut(); N];
+        for (many_idx, (value, key)) in values.iter_mut().zip(keys).enumerate() {
+            match self.get_mut(key) {
+                Some(v) => *value = v,
+                None => return Err(GetManyKeysError::Missing { many_idx }),
+            }
+        }
+        // NB: `Hash` and `Eq` are safe to implement, so keys that are not
+        // equal to each other can still find the same entry, e.g. if `Eq` is
+        // not transitive. Only the pointers themselves can rule that out.
+        for (i, &value) in values.iter().enumerate() {
+            for (j, &value2) in values[..i].iter().enumerate() {
+                if ptr::eq(value, value2) {
+                    return Err(GetManyKeysError::Duplicate {
+                        many_idx_1: j,
+                        many_idx_2: i,
+                    });
+                }
+            }
+        }
+        // SAFETY: The pointers are to distinct values, and the map has not
+        // been modified since they were taken.
+        Ok(values.map(|v| unsafe { &mut *v }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(len: u32) -> HashMap<u32, u32> {
+        (0..len).map(|k| (k * 2, k * 20)).collect()
+    }
+
+    #[test]
+    fn test_mut_normal() {
+        let mut m = map(100);
+        let [a, b, c] = m.index_many_mut([&120, &4, &60]);
+        assert_eq!((*a, *b, *c), (1200, 40, 600));
+        std::mem::swap(a, b);
+        *c += 1;
+        assert_eq!((m[&4], m[&60], m[&120]), (1200, 601, 40));
+    }
+
+    #[test]
+    fn test_ref_normal() {
+        let m = map(10);
+        assert_eq!(m.index_many([&18, &0]), [&180, &0]);
+        assert_eq!(m.get_many([&2, &4, &6]), Some([&20, &40, &60]));
+    }
+
+    #[test]
+    fn test_borrowed_keys() {
+        let mut m: HashMap<String, i32> = HashMap::new();
+        m.insert("x".into(), 1);
+        m.insert("y".into(), 2);
+        let [y, x] = m.index_many_mut(["y", "x"]);
+        *x += *y;
+        assert_eq!(m["x"], 3);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut m = map(3);
+        let [] = m.index_many_mut::<u32, 0>([]);
+        let mut empty: HashMap<u32, u32> = HashMap::new();
+        assert!(empty.get_many_mut([&0]).is_none());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut m = map(10);
+        assert_eq!(
+            m.try_get_many_mut([&2, &3]).unwrap_err(),
+            GetManyKeysError::Missing { many_idx: 1 }
+        );
+        assert_eq!(
+            m.try_get_many([&100, &2]).unwrap_err(),
+            GetManyKeysError::Missing { many_idx: 0 }
+        );
+        assert_eq!(
+            m.try_get_many_mut([&4, &2, &1, &2]).unwrap_err(),
+            GetManyKeysError::Duplicate {
+                many_idx_1: 1,
+                many_idx_2: 3
+            }
+        );
+        assert_eq!(
+            m.try_get_many([&1, &1]).unwrap_err(),
+            GetManyKeysError::Duplicate {
+                many_idx_1: 0,
+                many_idx_2: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_non_transitive_eq() {
+        // Keys that differ by at most one are equal, and all hashes collide.
+        #[derive(Debug)]
+        struct Near(i32);
+
+        impl PartialEq for Near {
+            fn eq(&self, other: &Self) -> bool {
+                (self.0 - other.0).abs() <= 1
+            }
+        }
+
+        impl Eq for Near {}
+
+        impl Hash for Near {
+            fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+        }
+
+        let mut m = HashMap::from([(Near(1), 10)]);
+        assert_eq!(
+            m.try_get_many_mut([&Near(0), &Near(2)]).unwrap_err(),
+            GetManyKeysError::Duplicate {
+                many_idx_1: 0,
+                many_idx_2: 1
+            }
+        );
+        assert_eq!(m.index_many_mut([&Near(2)]), [&mut 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Key at position 2 is not in the map (count 3)")]
+    fn test_missing_panic() {
+        let mut m = map(10);
+        m.index_many_mut([&2, &4, &5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys at position 0 and 2 are equal (count 3)")]
+    fn test_duplicate_panic() {
+        let m = map(10);
+        m.index_many([&2, &4, &2]);
+    }
+}
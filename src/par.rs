@@ -0,0 +1,356 @@
+//! Functions for processing many disjoint index groups on several threads.
+//!
+//! The groups are checked to be mutually disjoint up front, after which each
+//! thread gets exclusive access to the elements of its groups. Only
+//! [`std::thread::scope`] is used, no external runtime.
+//!
+//! # Example
+//! ```
+//! use index_many::par::par_for_each_many_mut;
+//!
+//! let mut v: Vec<i32> = (0..8).collect();
+//! let groups = [[0, 7], [1, 6], [2, 5], [3, 4]];
+//! par_for_each_many_mut(&mut v, &groups, 2, |[a, b]| std::mem::swap(a, b));
+//! assert_eq!(v, vec![7, 6, 5, 4, 3, 2, 1, 0]);
+//! ```
+//!
+//! Groups that are not disjoint can be split into waves of disjoint groups
+//! with [`schedule_disjoint()`].
+
+use std::collections::HashMap;
+use std::thread;
+
+/// A pointer to a slice whose elements are handed out to several threads.
+#[derive(Copy, Clone)]
+struct SlicePtr<T>(*mut [T]);
+
+// SAFETY: Each element is only accessed by the single thread that was handed
+// the group containing it.
+unsafe impl<T: Send> Send for SlicePtr<T> {}
+unsafe impl<T: Send> Sync for SlicePtr<T> {}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn disjoint_check_fail<const N: usize>(groups: &[[usize; N]], len: usize) -> ! {
+    let mut owner = vec![None; len];
+    for (g, group) in groups.iter().enumerate() {
+        for (i, &idx) in group.iter().enumerate() {
+            if idx >= len {
+                panic!(
+                    "Index {} is out of bounds of slice with len {} (group {}, position {})",
+                    idx, len, g, i,
+                );
+            }
+            if let Some(other) = owner[idx] {
+                panic!(
+                    "Index {} appears more than once (groups {} and {})",
+                    idx, other, g,
+                );
+            }
+            owner[idx] = Some(g);
+        }
+    }
+
+    // Fallthrough case, in case we missed anything above
+    panic!("Groups are not disjoint for a slice with len {}", len);
+}
+
+/// Returns `true` if all indices of `groups` are in bounds and unique.
+fn check_groups_disjoint<const N: usize>(groups: &[[usize; N]], len: usize) -> bool {
+    let mut seen = vec![false; len];
+    for &idx in groups.iter().flatten() {
+        match seen.get_mut(idx) {
+            Some(seen) if !*seen => *seen = true,
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Calls `f` with mutable references to the elements of each group, using
+/// up to `threads` threads.
+///
+/// The groups are split into contiguous batches, one per thread. Within a
+/// batch, the groups are processed in order.
+///
+/// Checking that the groups are disjoint takes `O(slice.len())` extra memory
+/// and time, plus `O(1)` per index.
+///
+/// # Panics
+/// Panics if `threads == 0`, if any index is out of bounds, or if any index
+/// appears more than once across all groups. Panics from `f` are propagated.
+pub fn par_for_each_many_mut<T, F, const N: usize>(
+    slice: &mut [T],
+    groups: &[[usize; N]],
+    threads: usize,
+    f: F,
+) where
+    T: Send,
+    F: Fn([&mut T; N]) + Sync,
+{
+    assert!(threads != 0, "thread count must be non-zero");
+    if !check_groups_disjoint(groups, slice.len()) {
+        disjoint_check_fail(groups, slice.len());
+    }
+
+    let slice = SlicePtr(slice as *mut [T]);
+    let run = |batch: &[[usize; N]]| {
+        for &group in batch {
+            // SAFETY: The groups are in bounds and mutually disjoint, so no
+            // element is handed out twice, on this thread or any other.
+            unsafe { f(crate::get_many_internal_mut(slice.0, group)) };
+        }
+    };
+
+    let batch_len = groups.len().div_ceil(threads).max(1);
+    if batch_len >= groups.len() {
+        return run(groups);
+    }
+
+    thread::scope(|s| {
+        let run = &run;
+        let mut batches = groups.chunks(batch_len);
+        let first = batches.next();
+        for batch in batches {
+            s.spawn(move || run(batch));
+        }
+        if let Some(batch) = first {
+            run(batch);
+        }
+    });
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn group_duplicate_fail(idx: usize, group: usize, i: usize, j: usize) -> ! {
+    panic!(
+        "Index {} appears more than once in group {} (positions {} and {})",
+        idx, group, i, j,
+    );
+}
+
+/// Splits `groups` into waves of mutually disjoint groups, and returns the
+/// positions of the groups in each wave.
+///
+/// Each group is put into the first wave after all waves that contain a
+/// group sharing an element with it. This means that groups sharing an
+/// element stay in their original order, so processing the waves one after
+/// another has the same result as processing the groups in order. The number
+/// of waves is not necessarily minimal.
+///
+/// Within each wave, the positions are in ascending order. After validating
+/// `groups` once as a [`crate::batch::ValidatedBatch`], each wave can be run
+/// with [`crate::batch::ValidatedBatch::for_each_mut_at()`], without checking
+/// its groups again.
+///
+/// # Panics
+/// Panics if any group contains an index more than once.
+///
+/// # Example
+/// ```
+/// use index_many::batch::ValidatedBatch;
+/// use index_many::par::schedule_disjoint;
+///
+/// let mut v = vec![1; 4];
+/// let groups = [[0, 1], [2, 3], [1, 2], [3, 0]];
+/// let waves = schedule_disjoint(&groups);
+/// assert_eq!(waves, vec![vec![0, 1], vec![2, 3]]);
+///
+/// let batch = ValidatedBatch::new(&groups, v.len()).unwrap();
+/// for wave in &waves {
+///     batch.for_each_mut_at(&mut v, wave, |[a, b]| *b += *a);
+/// }
+/// assert_eq!(v, vec![3, 2, 3, 2]);
+/// ```
+pub fn schedule_disjoint<const N: usize>(groups: &[[usize; N]]) -> Vec<Vec<usize>> {
+    // The first wave that does not contain each element yet, if any wave
+    // contains it.
+    let mut next_wave: HashMap<usize, usize> = HashMap::new();
+    let mut waves: Vec<Vec<usize>> = Vec::new();
+
+    for (g, group) in groups.iter().enumerate() {
+        for i in 0..N {
+            for j in 0..i {
+                if group[i] == group[j] {
+                    group_duplicate_fail(group[i], g, j, i);
+                }
+            }
+        }
+
+        let wave = group
+            .iter()
+            .map(|idx| next_wave.get(idx).copied().unwrap_or(0))
+            .max()
+            .unwrap_or(0);
+        for &idx in group {
+            next_wave.insert(idx, wave + 1);
+        }
+
+        if wave == waves.len() {
+            waves.push(Vec::new());
+        }
+        waves[wave].push(g);
+    }
+
+    waves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{par_for_each_many_mut, schedule_disjoint};
+    use crate::batch::ValidatedBatch;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_normal() {
+        let mut v: Vec<usize> = (0..100).collect();
+        let groups: Vec<[usize; 3]> = (0..33).map(|i| [i, 99 - i, 33 + i]).collect();
+        let calls = AtomicUsize::new(0);
+        par_for_each_many_mut(&mut v, &groups, 4, |[a, b, c]| {
+            *c += *a + *b;
+            *a = 0;
+            calls.fetch_add(1, Ordering::Relaxed);
+        });
+        assert_eq!(calls.load(Ordering::Relaxed), 33);
+        for i in 0..33 {
+            assert_eq!(v[i], 0);
+            assert_eq!(v[33 + i], 33 + i + 99);
+        }
+        assert_eq!(v[99], 99);
+    }
+
+    #[test]
+    fn test_thread_counts() {
+        for threads in [1, 2, 3, 7, 100] {
+            let mut v = vec![0; 10];
+            let groups = [[0, 1], [2, 3], [4, 5], [6, 7], [8, 9]];
+            par_for_each_many_mut(&mut v, &groups, threads, |[a, b]| {
+                *a += 1;
+                *b += 2;
+            });
+            assert_eq!(v, vec![1, 2, 1, 2, 1, 2, 1, 2, 1, 2]);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut v = vec![1, 2, 3];
+        par_for_each_many_mut(&mut v, &[], 4, |[_, _]: [&mut i32; 2]| unreachable!());
+        par_for_each_many_mut(&mut v, &[[], []], 4, |[]: [&mut i32; 0]| {});
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 2 appears more than once (groups 0 and 2)")]
+    fn test_overlap() {
+        let mut v = vec![1, 2, 3, 4, 5, 6];
+        par_for_each_many_mut(&mut v, &[[0, 2], [1, 3], [4, 2]], 2, |[_, _]| {});
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 1 appears more than once (groups 1 and 1)")]
+    fn test_duplicate_in_group() {
+        let mut v = vec![1, 2, 3, 4, 5, 6];
+        par_for_each_many_mut(&mut v, &[[0, 2], [1, 1]], 2, |[_, _]| {});
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 6 is out of bounds of slice with len 6 (group 1, position 0)")]
+    fn test_oob() {
+        let mut v = vec![1, 2, 3, 4, 5, 6];
+        par_for_each_many_mut(&mut v, &[[0, 2], [6, 1]], 2, |[_, _]| {});
+    }
+
+    #[test]
+    #[should_panic(expected = "thread count must be non-zero")]
+    fn test_zero_threads() {
+        let mut v = vec![1, 2];
+        par_for_each_many_mut(&mut v, &[[0, 1]], 0, |[_, _]| {});
+    }
+
+    fn check_schedule<const N: usize>(groups: &[[usize; N]], waves: &[Vec<usize>]) {
+        let mut scheduled: Vec<usize> = waves.iter().flatten().copied().collect();
+        scheduled.sort_unstable();
+        assert_eq!(scheduled, (0..groups.len()).collect::<Vec<_>>());
+
+        let mut wave_of = vec![0; groups.len()];
+        for (w, wave) in waves.iter().enumerate() {
+            assert!(!wave.is_empty());
+            let mut indices: Vec<usize> = wave.iter().flat_map(|&g| groups[g]).collect();
+            let count = indices.len();
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(indices.len(), count, "wave {} is not disjoint", w);
+            for &g in wave {
+                wave_of[g] = w;
+            }
+        }
+
+        for (a, group_a) in groups.iter().enumerate() {
+            for (b, group_b) in groups.iter().enumerate().skip(a + 1) {
+                if group_a.iter().any(|idx| group_b.contains(idx)) {
+                    assert!(wave_of[a] < wave_of[b]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_schedule() {
+        let groups = [[0, 1], [2, 3], [1, 2], [4, 5], [3, 4], [0, 5], [6, 7]];
+        let waves = schedule_disjoint(&groups);
+        check_schedule(&groups, &waves);
+        assert_eq!(waves, vec![vec![0, 1, 3, 6], vec![2, 4, 5]]);
+    }
+
+    #[test]
+    fn test_schedule_stencil() {
+        // A 1d three point stencil over 20 elements
+        let groups: Vec<[usize; 3]> = (1..19).map(|i| [i - 1, i, i + 1]).collect();
+        let waves = schedule_disjoint(&groups);
+        check_schedule(&groups, &waves);
+
+        // Same result as sequential Gauss-Seidel sweeps
+        let mut seq: Vec<u64> = (0..20).map(|i| i * i % 7).collect();
+        let mut waved = seq.clone();
+        let update = |[a, b, c]: [&mut u64; 3]| *b = (*a + *b * 2 + *c) / 3 + 1;
+        for &[a, b, c] in &groups {
+            let [a, b, c] = crate::generic::index_many_mut(&mut seq, [a, b, c]);
+            update([a, b, c]);
+        }
+        let batch = ValidatedBatch::new(&groups, waved.len()).unwrap();
+        for wave in &waves {
+            batch.for_each_mut_at(&mut waved, wave, update);
+        }
+        assert_eq!(seq, waved);
+    }
+
+    #[test]
+    fn test_schedule_generated() {
+        let mut state = 1usize;
+        let groups: Vec<[usize; 3]> = (0..200)
+            .map(|_| {
+                let mut group = [0; 3];
+                for (i, idx) in group.iter_mut().enumerate() {
+                    state = (state * 1103515245 + 12345) % (1 << 31);
+                    *idx = (state >> 8) % 32 * 3 + i;
+                }
+                group
+            })
+            .collect();
+        check_schedule(&groups, &schedule_disjoint(&groups));
+    }
+
+    #[test]
+    fn test_schedule_empty() {
+        assert!(schedule_disjoint::<2>(&[]).is_empty());
+        assert_eq!(schedule_disjoint(&[[], []]), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 3 appears more than once in group 1 (positions 0 and 2)")]
+    fn test_schedule_duplicate() {
+        schedule_disjoint(&[[0, 1, 2], [3, 1, 3]]);
+    }
+}
@@ -0,0 +1,140 @@
+//! A variant of the generic API that projects a pinned slice to pinned elements.
+//!
+//! Pinning a slice pins each of its elements: there is no way to move an
+//! element out of a `Pin<&mut [T]>` without unsafe code, and the slice can not
+//! be resized or reallocated through it. So once the indices have been
+//! validated, wrapping each element reference in `Pin` again is sound.
+//!
+//! # Example
+//! ```
+//! use std::pin::Pin;
+//! use index_many::pin::index_many_pin_mut;
+//!
+//! let mut v = vec![1, 2, 3, 4, 5];
+//! let [a, b] = index_many_pin_mut(Pin::new(&mut v[..]), [1, 3]);
+//! *a.get_mut() += 10;
+//! *b.get_mut() += 100;
+//! assert_eq!(v, vec![1, 12, 3, 104, 5]);
+//! ```
+
+use std::pin::Pin;
+
+use crate::generic::Indices;
+
+/// # Safety
+/// The indices have to be valid for `slice`, as checked by [`Indices::is_valid()`].
+pub unsafe fn index_many_pin_mut_unchecked<T, I: Indices<N>, const N: usize>(
+    slice: Pin<&mut [T]>,
+    indices: I,
+) -> [Pin<&mut T>; N] {
+    // SAFETY: The elements of a pinned slice are pinned as well, see the
+    // module docs. None of them is moved here.
+    let slice = slice.get_unchecked_mut();
+    crate::generic::index_many_mut_unchecked(slice, indices).map(|r| Pin::new_unchecked(r))
+}
+
+pub fn get_many_pin_mut<T, I: Indices<N>, const N: usize>(
+    slice: Pin<&mut [T]>,
+    indices: I,
+) -> Option<[Pin<&mut T>; N]> {
+    if !indices.is_valid(slice.len()) {
+        return None;
+    }
+    unsafe { Some(index_many_pin_mut_unchecked(slice, indices)) }
+}
+
+pub fn index_many_pin_mut<T, I: Indices<N>, const N: usize>(
+    slice: Pin<&mut [T]>,
+    indices: I,
+) -> [Pin<&mut T>; N] {
+    let len = slice.len();
+    if !indices.is_valid(len) {
+        indices.cause_invalid_panic(len)
+    }
+    unsafe { index_many_pin_mut_unchecked(slice, indices) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::marker::PhantomPinned;
+
+    use crate::generic::UnsortedIndices;
+
+    struct Node {
+        value: i32,
+        _pinned: PhantomPinned,
+    }
+
+    impl Node {
+        fn new(value: i32) -> Self {
+            Node {
+                value,
+                _pinned: PhantomPinned,
+            }
+        }
+
+        fn add(self: Pin<&mut Self>, x: i32) {
+            // SAFETY: `value` is not structurally pinned.
+            unsafe { self.get_unchecked_mut().value += x }
+        }
+    }
+
+    fn values(nodes: &[Node]) -> Vec<i32> {
+        nodes.iter().map(|n| n.value).collect()
+    }
+
+    #[test]
+    fn test_mut_normal() {
+        let mut nodes: Pin<Box<[Node]>> = (1..=5).map(Node::new).collect::<Box<[_]>>().into();
+        let [a, b, c] = index_many_pin_mut(nodes.as_mut(), [0, 2, 4]);
+        a.add(10);
+        b.add(100);
+        c.add(1000);
+        assert_eq!(values(&nodes), vec![11, 2, 103, 4, 1005]);
+    }
+
+    #[test]
+    fn test_mut_unsorted() {
+        let mut nodes: Pin<Box<[Node]>> = (1..=5).map(Node::new).collect::<Box<[_]>>().into();
+        let [a, b] = index_many_pin_mut(nodes.as_mut(), UnsortedIndices([3, 1]));
+        let x = a.value;
+        b.add(x);
+        assert_eq!(values(&nodes), vec![1, 6, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_mut_empty() {
+        let mut v = vec![1, 2, 3];
+        let [] = index_many_pin_mut(Pin::new(&mut v[..]), []);
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_get_invalid() {
+        let mut nodes: Pin<Box<[Node]>> = (1..=5).map(Node::new).collect::<Box<[_]>>().into();
+        assert!(get_many_pin_mut(nodes.as_mut(), [0, 5]).is_none());
+        assert!(get_many_pin_mut(nodes.as_mut(), [3, 1]).is_none());
+        assert!(get_many_pin_mut(nodes.as_mut(), UnsortedIndices([1, 1])).is_none());
+        assert!(get_many_pin_mut(nodes.as_mut(), UnsortedIndices([3, 1])).is_some());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 5 is out of bounds of slice with len 5 (indices [5], position 0)"
+    )]
+    fn test_mut_oob() {
+        let mut v = [1, 2, 3, 4, 5];
+        index_many_pin_mut(Pin::new(&mut v[..]), [5]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 3 appears more than once (indices [1, 3, 3, 4], position 1 and 2)"
+    )]
+    fn test_mut_duplicate() {
+        let mut v = [1, 2, 3, 4, 5];
+        index_many_pin_mut(Pin::new(&mut v[..]), [1, 3, 3, 4]);
+    }
+}
@@ -0,0 +1,217 @@
+//! A variant of the API that works on raw slice pointers, and returns raw element pointers.
+//!
+//! No references are created, neither to the slice nor to its elements, so the
+//! returned pointers don't invalidate each other or any other pointers
+//! into the slice under Stacked Borrows.
+//!
+//! Since raw pointers are allowed to alias, the plain functions only check that
+//! the indices are in bounds. The `_unique` functions additionally check the
+//! indices like [`crate::generic::get_many()`] does.
+//!
+//! # Example
+//! ```
+//! use index_many::ptr::get_many_ptr;
+//!
+//! let mut v = vec![1, 2, 3, 4, 5];
+//! let slice: *mut [i32] = &mut v[..];
+//! let [a, b] = get_many_ptr(slice, [4, 1]).unwrap();
+//! unsafe {
+//!     *a += 10;
+//!     *b += *a;
+//! }
+//! assert_eq!(v, vec![1, 17, 3, 4, 15]);
+//! ```
+
+use std::ptr::NonNull;
+
+use crate::generic::Indices;
+
+#[inline]
+fn get_many_ptr_internal<T, const N: usize>(slice: *mut [T], indices: [usize; N]) -> [*mut T; N] {
+    // NB: `wrapping_add` keeps this safe for arbitrary slice pointers. For
+    // indices that are in bounds, it computes the same pointers as `add`.
+    let base = slice.as_mut_ptr();
+    indices.map(|idx| base.wrapping_add(idx))
+}
+
+/// Returns pointers to the elements at `indices`, without checking them.
+///
+/// Indices that are out of bounds result in pointers that are not valid
+/// for reads or writes.
+pub fn get_many_ptr_unchecked<T, const N: usize>(
+    slice: *mut [T],
+    indices: [usize; N],
+) -> [*mut T; N] {
+    get_many_ptr_internal(slice, indices)
+}
+
+/// Returns pointers to the elements at `indices`, if they are all in bounds.
+///
+/// The indices can be in any order, and may contain duplicates.
+pub fn get_many_ptr<T, const N: usize>(
+    slice: *mut [T],
+    indices: [usize; N],
+) -> Option<[*mut T; N]> {
+    if !crate::check_indices_in_bounds(&indices, slice.len()) {
+        return None;
+    }
+    Some(get_many_ptr_internal(slice, indices))
+}
+
+/// Returns pointers to the elements at `indices`, if they are valid as
+/// checked by `I`.
+///
+/// For all indices types of this crate, this means that the pointers are
+/// also guaranteed to be distinct.
+pub fn get_many_ptr_unique<T, I: Indices<N>, const N: usize>(
+    slice: *mut [T],
+    indices: I,
+) -> Option<[*mut T; N]> {
+    if !indices.is_valid(slice.len()) {
+        return None;
+    }
+    Some(get_many_ptr_internal(slice, indices.to_raw_indices()))
+}
+
+/// Returns pointers to the elements at `indices`, without checking them.
+///
+/// # Safety
+/// All indices have to be in bounds of `slice`.
+pub unsafe fn get_many_non_null_unchecked<T, const N: usize>(
+    slice: NonNull<[T]>,
+    indices: [usize; N],
+) -> [NonNull<T>; N] {
+    get_many_ptr_internal(slice.as_ptr(), indices).map(|ptr| NonNull::new_unchecked(ptr))
+}
+
+/// Returns pointers to the elements at `indices`, if they are all in bounds.
+///
+/// The indices can be in any order, and may contain duplicates.
+pub fn get_many_non_null<T, const N: usize>(
+    slice: NonNull<[T]>,
+    indices: [usize; N],
+) -> Option<[NonNull<T>; N]> {
+    if !crate::check_indices_in_bounds(&indices, slice.len()) {
+        return None;
+    }
+    // SAFETY: We checked that all indices are in bounds.
+    unsafe { Some(get_many_non_null_unchecked(slice, indices)) }
+}
+
+/// Returns pointers to the elements at `indices`, if they are valid as
+/// checked by `I`.
+///
+/// For all indices types of this crate, this means that the pointers are
+/// also guaranteed to be distinct.
+pub fn get_many_non_null_unique<T, I: Indices<N>, const N: usize>(
+    slice: NonNull<[T]>,
+    indices: I,
+) -> Option<[NonNull<T>; N]> {
+    if !indices.is_valid(slice.len()) {
+        return None;
+    }
+    // SAFETY: `Indices::is_valid` checked that all indices are in bounds.
+    unsafe { Some(get_many_non_null_unchecked(slice, indices.to_raw_indices())) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generic::UnsortedIndices;
+
+    #[test]
+    fn test_ptr_normal() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let slice: *mut [i32] = &mut v[..];
+        let [a, b, c] = get_many_ptr(slice, [0, 2, 4]).unwrap();
+        unsafe {
+            *a += 10;
+            *b += 100;
+            *c += 1000;
+        }
+        assert_eq!(v, vec![11, 2, 103, 4, 1005]);
+    }
+
+    #[test]
+    fn test_ptr_interleaved_writes() {
+        // Interleaving writes through all pointers would be rejected by
+        // Stacked Borrows if any of them were derived from a reference
+        // created for another one.
+        let mut v = vec![0; 4];
+        let slice: *mut [i32] = &mut v[..];
+        let [a, b, c] = get_many_ptr(slice, [3, 0, 1]).unwrap();
+        unsafe {
+            *a = 1;
+            *b = *a + 1;
+            *c = *b + *a;
+            *a = *c * 10;
+        }
+        assert_eq!(v, vec![2, 3, 0, 30]);
+    }
+
+    #[test]
+    fn test_ptr_duplicate() {
+        let mut v = vec![1, 2, 3];
+        let slice: *mut [i32] = &mut v[..];
+        let [a, b] = get_many_ptr(slice, [1, 1]).unwrap();
+        assert_eq!(a, b);
+        unsafe {
+            *a += 1;
+            *b += 1;
+        }
+        assert_eq!(v, vec![1, 4, 3]);
+    }
+
+    #[test]
+    fn test_ptr_oob() {
+        let mut v = [1, 2, 3];
+        let slice: *mut [i32] = &mut v[..];
+        assert!(get_many_ptr(slice, [0, 3]).is_none());
+        assert!(get_many_ptr(slice, [usize::MAX]).is_none());
+        assert!(get_many_ptr(slice, []).is_some());
+    }
+
+    #[test]
+    fn test_ptr_dangling() {
+        // Computing pointers into a dangling slice is fine,
+        // as long as they are not dereferenced.
+        let slice = std::ptr::slice_from_raw_parts_mut(NonNull::<u64>::dangling().as_ptr(), 8);
+        let [a, b] = get_many_ptr(slice, [7, 2]).unwrap();
+        assert_eq!(a as usize - b as usize, 5 * std::mem::size_of::<u64>());
+        assert!(get_many_ptr(slice, [8]).is_none());
+    }
+
+    #[test]
+    fn test_ptr_unique() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let slice: *mut [i32] = &mut v[..];
+        assert!(get_many_ptr_unique(slice, [1, 1]).is_none());
+        assert!(get_many_ptr_unique(slice, [2, 1]).is_none());
+        assert!(get_many_ptr_unique(slice, UnsortedIndices([2, 2])).is_none());
+        assert!(get_many_ptr_unique(slice, [1, 5]).is_none());
+
+        let [a, b] = get_many_ptr_unique(slice, UnsortedIndices([4, 1])).unwrap();
+        unsafe {
+            std::ptr::swap(a, b);
+        }
+        assert_eq!(v, vec![1, 5, 3, 4, 2]);
+    }
+
+    #[test]
+    fn test_non_null() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let slice = NonNull::from(&mut v[..]);
+        let [a, b] = get_many_non_null(slice, [3, 3]).unwrap();
+        assert_eq!(a, b);
+        assert!(get_many_non_null(slice, [0, 5]).is_none());
+        assert!(get_many_non_null_unique(slice, [3, 3]).is_none());
+
+        let [mut a, mut b] = get_many_non_null_unique(slice, UnsortedIndices([3, 0])).unwrap();
+        unsafe {
+            *a.as_mut() += 10;
+            *b.as_mut() += *a.as_ref();
+        }
+        assert_eq!(v, vec![15, 2, 3, 14, 5]);
+    }
+}
@@ -8,7 +8,7 @@
 //!
 
 #[inline]
-fn check_indices_valid(indices: &[usize], len: usize) -> bool {
+pub(crate) fn check_indices_valid(indices: &[usize], len: usize) -> bool {
     let mut valid = true;
 
     for &[a, b] in indices.array_windows() {
@@ -5,9 +5,15 @@
 //! # Example codegen
 //! See [`crate::_doc_assembly::checked_usize_trait()`]
 
+mod const_range;
 mod ranges;
 mod single_usize;
 
+pub use const_range::ConstRange;
+pub(crate) use ranges::{
+    check_range_indices_valid, overlapping_range_check_fail, range_check_fail,
+};
+
 pub trait SliceIndices<T: ?Sized, const N: usize>: Sized {
     type Output: ?Sized;
 
@@ -0,0 +1,155 @@
+use std::ops::Range;
+
+use super::*;
+
+/// The range `start..start + K`, which indexes a fixed-size `[T; K]` array.
+///
+/// # Example
+/// ```
+/// use index_many::slice_index::{index_many_mut, ConstRange};
+///
+/// let mut v = vec![1, 2, 3, 4, 5, 6];
+/// let [a, b]: [&mut [i32; 2]; 2] = index_many_mut(&mut v, [ConstRange(0), ConstRange(3)]);
+/// std::mem::swap(a, b);
+/// assert_eq!(v, vec![4, 5, 3, 1, 2, 6]);
+/// ```
+///
+/// # Example codegen
+/// See [`crate::_doc_assembly::checked_const_range_trait()`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConstRange<const K: usize>(pub usize);
+
+#[inline]
+fn to_ranges<const K: usize, const N: usize>(indices: &[ConstRange<K>; N]) -> [Range<usize>; N] {
+    // NB: Saturating is fine here, since a saturated end can
+    // never be in bounds of a slice.
+    indices.map(|ConstRange(start)| start..start.saturating_add(K))
+}
+
+unsafe fn get_many_internal<'a, T, const K: usize, const N: usize>(
+    slice: *const [T],
+    indices: [ConstRange<K>; N],
+) -> [&'a [T; K]; N] {
+    let base = slice.as_ptr();
+    indices.map(|ConstRange(start)| &*(base.add(start) as *const [T; K]))
+}
+
+unsafe fn get_many_internal_mut<'a, T, const K: usize, const N: usize>(
+    slice: *mut [T],
+    indices: [ConstRange<K>; N],
+) -> [&'a mut [T; K]; N] {
+    let base = slice.as_mut_ptr();
+    indices.map(|ConstRange(start)| &mut *(base.add(start) as *mut [T; K]))
+}
+
+impl<T, const K: usize, const N: usize> SliceIndices<[T], N> for ConstRange<K> {
+    type Output = [T; K];
+
+    unsafe fn get_many_unchecked(indices: [Self; N], slice: &[T]) -> [&Self::Output; N] {
+        get_many_internal(slice, indices)
+    }
+    unsafe fn get_many_unchecked_mut(
+        indices: [Self; N],
+        slice: &mut [T],
+    ) -> [&mut Self::Output; N] {
+        get_many_internal_mut(slice, indices)
+    }
+
+    fn get_many(indices: [Self; N], slice: &[T]) -> Option<[&Self::Output; N]> {
+        if check_range_indices_valid(&to_ranges(&indices), slice.len()) {
+            // SAFETY: We checked that the ranges are valid
+            // with `check_range_indices_valid`
+            unsafe { Some(Self::get_many_unchecked(indices, slice)) }
+        } else {
+            None
+        }
+    }
+    fn get_many_mut(indices: [Self; N], slice: &mut [T]) -> Option<[&mut Self::Output; N]> {
+        if check_range_indices_valid(&to_ranges(&indices), slice.len()) {
+            // SAFETY: We checked that the ranges are valid
+            // with `check_range_indices_valid`
+            unsafe { Some(Self::get_many_unchecked_mut(indices, slice)) }
+        } else {
+            None
+        }
+    }
+
+    fn index_many(indices: [Self; N], slice: &[T]) -> [&Self::Output; N] {
+        let ranges = to_ranges(&indices);
+        if check_range_indices_valid(&ranges, slice.len()) {
+            // SAFETY: We checked that the ranges are valid
+            // with `check_range_indices_valid`
+            unsafe { Self::get_many_unchecked(indices, slice) }
+        } else {
+            range_check_fail(&ranges, slice.len())
+        }
+    }
+    fn index_many_mut(indices: [Self; N], slice: &mut [T]) -> [&mut Self::Output; N] {
+        let ranges = to_ranges(&indices);
+        if check_range_indices_valid(&ranges, slice.len()) {
+            // SAFETY: We checked that the ranges are valid
+            // with `check_range_indices_valid`
+            unsafe { Self::get_many_unchecked_mut(indices, slice) }
+        } else {
+            range_check_fail(&ranges, slice.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_mut_normal() {
+        let mut v = vec![1, 2, 3, 4, 5, 6, 7];
+        let [a, b] = index_many_mut(&mut v, [ConstRange::<2>(1), ConstRange(4)]);
+        a[0] += 10;
+        b[1] += 100;
+        assert_eq!(v, vec![1, 12, 3, 4, 5, 106, 7]);
+    }
+
+    #[test]
+    fn test_ref_normal() {
+        let v = vec![1, 2, 3, 4, 5, 6, 7];
+        let [a, b, c] = index_many(&v, [ConstRange(0), ConstRange(2), ConstRange(4)]);
+        assert_eq!((a, b, c), (&[1, 2], &[3, 4], &[5, 6]));
+    }
+
+    #[test]
+    fn test_adjacent_and_empty() {
+        let mut v = vec![1, 2, 3, 4];
+        let [a, b] = index_many_mut(&mut v, [ConstRange::<2>(0), ConstRange(2)]);
+        a.swap_with_slice(b);
+        assert_eq!(v, vec![3, 4, 1, 2]);
+
+        let [a, b] = index_many(&v, [ConstRange::<0>(1), ConstRange(1)]);
+        assert_eq!((a, b), (&[], &[]));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let v = vec![1, 2, 3, 4, 5];
+        assert!(get_many(&v, [ConstRange::<2>(0), ConstRange(1)]).is_none());
+        assert!(get_many(&v, [ConstRange::<2>(3), ConstRange(0)]).is_none());
+        assert!(get_many(&v, [ConstRange::<2>(0), ConstRange(4)]).is_none());
+        assert!(get_many(&v, [ConstRange::<2>(0), ConstRange(usize::MAX)]).is_none());
+        assert!(get_many(&v, [ConstRange::<2>(0), ConstRange(3)]).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Ranges [0..3, 2..5] overlap or are not sorted")]
+    fn test_overlap() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        index_many_mut(&mut v, [ConstRange::<3>(0), ConstRange(2)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Range 4..6 is out of bounds of slice with len 5 (ranges [0..2, 4..6], at 1)"
+    )]
+    fn test_oob() {
+        let v = vec![1, 2, 3, 4, 5];
+        index_many(&v, [ConstRange::<2>(0), ConstRange(4)]);
+    }
+}
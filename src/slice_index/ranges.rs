@@ -5,7 +5,21 @@ use super::*;
 #[inline(never)]
 #[cold]
 #[track_caller]
-fn range_check_fail(indices: &[Range<usize>], len: usize) -> ! {
+pub(crate) fn range_check_fail(indices: &[Range<usize>], len: usize) -> ! {
+    range_check_fail_impl(indices, len, true)
+}
+
+/// Like [`range_check_fail()`], for ranges that may be given in any order,
+/// and may overlap.
+#[inline(never)]
+#[cold]
+#[track_caller]
+pub(crate) fn overlapping_range_check_fail(indices: &[Range<usize>], len: usize) -> ! {
+    range_check_fail_impl(indices, len, false)
+}
+
+#[track_caller]
+fn range_check_fail_impl(indices: &[Range<usize>], len: usize, disjoint: bool) -> ! {
     // Check that all ranges are valid
     for (i, idx) in indices.iter().enumerate() {
         if idx.start > idx.end {
@@ -17,9 +31,11 @@ fn range_check_fail(indices: &[Range<usize>], len: usize) -> ! {
     }
 
     // Check that the ranges are sorted and don't overlap
-    for [a, b] in indices.array_windows() {
-        if a.end > b.start {
-            panic!("Ranges {:?} overlap or are not sorted", indices);
+    if disjoint {
+        for [a, b] in indices.array_windows() {
+            if a.end > b.start {
+                panic!("Ranges {:?} overlap or are not sorted", indices);
+            }
         }
     }
 
@@ -41,7 +57,10 @@ fn range_check_fail(indices: &[Range<usize>], len: usize) -> ! {
 }
 
 #[inline]
-fn check_range_indices_valid<const N: usize>(indices: &[Range<usize>; N], len: usize) -> bool {
+pub(crate) fn check_range_indices_valid<const N: usize>(
+    indices: &[Range<usize>; N],
+    len: usize,
+) -> bool {
     let mut valid = true;
 
     for [a, b] in indices.array_windows() {
@@ -0,0 +1,407 @@
+//! A variant of the API for accessing a fixed pattern of signed offsets
+//! around a center index, as used by finite-difference and image kernels.
+//!
+//! The offsets are checked for uniqueness once, when the [`Stencil`] is
+//! created. After that, checking a center index needs only two comparisons:
+//! the center has to be at least as large as the most negative offset, and
+//! far enough from the end of the slice for the most positive offset.
+//!
+//! [`Stencil2d`] does the same for a 2D grid stored row by row, and also
+//! ensures that no offset wraps around into a neighbouring row.
+//!
+//! # Example
+//! ```
+//! use index_many::stencil::Stencil;
+//!
+//! let laplace = Stencil::new([-1, 0, 1]).unwrap();
+//! let mut v = vec![1, 4, 9, 16, 25];
+//! let [l, c, r] = laplace.index_many_mut(&mut v, 2);
+//! *c = *l + *r - 2 * *c;
+//! assert_eq!(v, vec![1, 4, 2, 16, 25]);
+//! assert!(laplace.get_many_mut(&mut v, 0).is_none());
+//! ```
+//!
+//! # Example codegen
+//! See [`crate::_doc_assembly::checked_stencil()`]
+
+use std::convert::TryFrom;
+
+/// A pattern of `N` unique signed offsets.
+#[derive(Copy, Clone, Debug)]
+pub struct Stencil<const N: usize> {
+    offsets: [isize; N],
+    max_neg: usize,
+    max_pos: usize,
+}
+
+#[derive(Debug)]
+pub struct StencilError {
+    _private: (),
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn stencil_check_fail(offsets: &[isize], center: usize, len: usize) -> ! {
+    panic!(
+        "Stencil with offsets {:?} at center {} is out of bounds of slice with len {}",
+        offsets, center, len
+    );
+}
+
+impl<const N: usize> Stencil<N> {
+    /// Creates a stencil, if all offsets are unique.
+    pub fn new(offsets: [isize; N]) -> Result<Self, StencilError> {
+        let mut valid = true;
+        for (i, &off) in offsets.iter().enumerate() {
+            for &off2 in &offsets[..i] {
+                valid &= off != off2;
+            }
+        }
+        if !valid {
+            return Err(StencilError { _private: () });
+        }
+
+        let mut max_neg = 0;
+        let mut max_pos = 0;
+        for &off in &offsets {
+            if off < 0 {
+                max_neg = max_neg.max(off.unsigned_abs());
+            } else {
+                max_pos = max_pos.max(off as usize);
+            }
+        }
+
+        Ok(Self {
+            offsets,
+            max_neg,
+            max_pos,
+        })
+    }
+
+    pub fn offsets(&self) -> &[isize; N] {
+        &self.offsets
+    }
+
+    /// Checks that all offsets around `center` are in bounds of a slice with
+    /// length `len`. This also requires `center` itself to be in bounds.
+    #[inline]
+    pub fn is_valid(&self, center: usize, len: usize) -> bool {
+        // NB: `center + max_pos < len` can not overflow in this form.
+        N == 0 || (center >= self.max_neg && center < len.saturating_sub(self.max_pos))
+    }
+
+    #[inline]
+    fn raw_indices(&self, center: usize) -> [usize; N] {
+        self.offsets.map(|off| center.wrapping_add(off as usize))
+    }
+
+    /// # Safety
+    /// [`Stencil::is_valid()`] has to hold for `center` and the length of `slice`.
+    pub unsafe fn index_many_unchecked<'a, T>(&self, slice: &'a [T], center: usize) -> [&'a T; N] {
+        crate::get_many_internal(slice, self.raw_indices(center))
+    }
+
+    /// # Safety
+    /// [`Stencil::is_valid()`] has to hold for `center` and the length of `slice`.
+    pub unsafe fn index_many_mut_unchecked<'a, T>(
+        &self,
+        slice: &'a mut [T],
+        center: usize,
+    ) -> [&'a mut T; N] {
+        crate::get_many_internal_mut(slice, self.raw_indices(center))
+    }
+
+    pub fn get_many<'a, T>(&self, slice: &'a [T], center: usize) -> Option<[&'a T; N]> {
+        if !self.is_valid(center, slice.len()) {
+            return None;
+        }
+        // SAFETY: The offsets are unique, and we checked that they are in bounds.
+        unsafe { Some(self.index_many_unchecked(slice, center)) }
+    }
+
+    pub fn get_many_mut<'a, T>(&self, slice: &'a mut [T], center: usize) -> Option<[&'a mut T; N]> {
+        if !self.is_valid(center, slice.len()) {
+            return None;
+        }
+        // SAFETY: The offsets are unique, and we checked that they are in bounds.
+        unsafe { Some(self.index_many_mut_unchecked(slice, center)) }
+    }
+
+    pub fn index_many<'a, T>(&self, slice: &'a [T], center: usize) -> [&'a T; N] {
+        let len = slice.len();
+        match self.get_many(slice, center) {
+            Some(s) => s,
+            None => stencil_check_fail(&self.offsets, center, len),
+        }
+    }
+
+    pub fn index_many_mut<'a, T>(&self, slice: &'a mut [T], center: usize) -> [&'a mut T; N] {
+        let len = slice.len();
+        match self.get_many_mut(slice, center) {
+            Some(s) => s,
+            None => stencil_check_fail(&self.offsets, center, len),
+        }
+    }
+}
+
+/// A pattern of `N` unique `(dx, dy)` offsets on a 2D grid that is stored
+/// row by row, with rows `stride` elements apart.
+///
+/// # Example
+/// ```
+/// use index_many::stencil::Stencil2d;
+///
+/// // A 3x3 grid
+/// let mut grid = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+/// let cross = Stencil2d::new([(0, -1), (-1, 0), (0, 0), (1, 0), (0, 1)], 3).unwrap();
+/// let [up, left, c, right, down] = cross.index_many_mut(&mut grid, 1, 1);
+/// *c = *up + *left + *right + *down;
+/// assert_eq!(grid[4], 16);
+/// // Offset `(-1, 0)` would wrap around into the previous row
+/// assert!(cross.get_many_mut(&mut grid, 0, 1).is_none());
+/// ```
+///
+/// # Example codegen
+/// See [`crate::_doc_assembly::option_stencil_2d()`]
+#[derive(Copy, Clone, Debug)]
+pub struct Stencil2d<const N: usize> {
+    linear: Stencil<N>,
+    offsets: [(isize, isize); N],
+    stride: usize,
+    max_neg_x: usize,
+    max_pos_x: usize,
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn stencil_2d_check_fail(
+    offsets: &[(isize, isize)],
+    stride: usize,
+    x: usize,
+    y: usize,
+    len: usize,
+) -> ! {
+    panic!(
+        "Stencil with offsets {:?} at ({}, {}) is out of bounds of grid with stride {} and len {}",
+        offsets, x, y, stride, len
+    );
+}
+
+impl<const N: usize> Stencil2d<N> {
+    /// Creates a 2D stencil, if all offsets are unique and fit in a row of
+    /// length `stride`.
+    pub fn new(offsets: [(isize, isize); N], stride: usize) -> Result<Self, StencilError> {
+        let mut max_neg_x = 0;
+        let mut max_pos_x = 0;
+        for &(dx, _) in &offsets {
+            if dx < 0 {
+                max_neg_x = max_neg_x.max(dx.unsigned_abs());
+            } else {
+                max_pos_x = max_pos_x.max(dx as usize);
+            }
+        }
+        if max_neg_x.saturating_add(max_pos_x) >= stride {
+            return Err(StencilError { _private: () });
+        }
+
+        // NB: Since all `dx` fit into a single row, the linear
+        // offsets are unique iff the `(dx, dy)` pairs are.
+        let stride_isize = isize::try_from(stride).map_err(|_| StencilError { _private: () })?;
+        let mut linear = [0; N];
+        for (lin, &(dx, dy)) in linear.iter_mut().zip(&offsets) {
+            *lin = dy
+                .checked_mul(stride_isize)
+                .and_then(|row| row.checked_add(dx))
+                .ok_or(StencilError { _private: () })?;
+        }
+
+        Ok(Self {
+            linear: Stencil::new(linear)?,
+            offsets,
+            stride,
+            max_neg_x,
+            max_pos_x,
+        })
+    }
+
+    pub fn offsets(&self) -> &[(isize, isize); N] {
+        &self.offsets
+    }
+
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns the index of `(x, y)` in a slice with length `len`,
+    /// if all offsets around it are in bounds.
+    #[inline]
+    fn center(&self, x: usize, y: usize, len: usize) -> Option<usize> {
+        let mut valid = true;
+        valid &= x >= self.max_neg_x;
+        valid &= x < self.stride - self.max_pos_x;
+        let center = y.checked_mul(self.stride)?.checked_add(x)?;
+        valid &= self.linear.is_valid(center, len);
+        if valid {
+            Some(center)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_many<'a, T>(&self, slice: &'a [T], x: usize, y: usize) -> Option<[&'a T; N]> {
+        let center = self.center(x, y, slice.len())?;
+        // SAFETY: The linear offsets are unique, and we checked that they
+        // are in bounds.
+        unsafe { Some(self.linear.index_many_unchecked(slice, center)) }
+    }
+
+    pub fn get_many_mut<'a, T>(
+        &self,
+        slice: &'a mut [T],
+        x: usize,
+        y: usize,
+    ) -> Option<[&'a mut T; N]> {
+        let center = self.center(x, y, slice.len())?;
+        // SAFETY: The linear offsets are unique, and we checked that they
+        // are in bounds.
+        unsafe { Some(self.linear.index_many_mut_unchecked(slice, center)) }
+    }
+
+    pub fn index_many<'a, T>(&self, slice: &'a [T], x: usize, y: usize) -> [&'a T; N] {
+        let len = slice.len();
+        match self.get_many(slice, x, y) {
+            Some(s) => s,
+            None => stencil_2d_check_fail(&self.offsets, self.stride, x, y, len),
+        }
+    }
+
+    pub fn index_many_mut<'a, T>(&self, slice: &'a mut [T], x: usize, y: usize) -> [&'a mut T; N] {
+        let len = slice.len();
+        match self.get_many_mut(slice, x, y) {
+            Some(s) => s,
+            None => stencil_2d_check_fail(&self.offsets, self.stride, x, y, len),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mut_normal() {
+        let stencil = Stencil::new([-2, 0, 1]).unwrap();
+        let mut v = vec![1, 2, 3, 4, 5];
+        let [a, b, c] = stencil.index_many_mut(&mut v, 2);
+        *a += 10;
+        *b += 100;
+        *c += 1000;
+        assert_eq!(v, vec![11, 2, 103, 1004, 5]);
+    }
+
+    #[test]
+    fn test_ref_unsorted() {
+        let stencil = Stencil::new([1, -1, 0]).unwrap();
+        let v = vec![1, 2, 3, 4, 5];
+        assert_eq!(stencil.index_many(&v, 1), [&3, &1, &2]);
+        assert_eq!(stencil.index_many(&v, 3), [&5, &3, &4]);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let stencil = Stencil::new([-2, 0, 1]).unwrap();
+        let v = vec![1, 2, 3, 4, 5];
+        assert!(stencil.get_many(&v, 1).is_none());
+        assert!(stencil.get_many(&v, 2).is_some());
+        assert!(stencil.get_many(&v, 3).is_some());
+        assert!(stencil.get_many(&v, 4).is_none());
+        assert!(stencil.get_many(&v, usize::MAX).is_none());
+        assert!(stencil.get_many(&v[..2], 1).is_none());
+    }
+
+    #[test]
+    fn test_one_sided() {
+        let forward = Stencil::new([1, 2]).unwrap();
+        let backward = Stencil::new([-1, -2]).unwrap();
+        let v = vec![1, 2, 3];
+        assert_eq!(forward.index_many(&v, 0), [&2, &3]);
+        assert!(forward.get_many(&v, 1).is_none());
+        assert_eq!(backward.index_many(&v, 2), [&2, &1]);
+        assert!(backward.get_many(&v, 1).is_none());
+        // The center itself has to be in bounds as well
+        assert!(backward.get_many(&v, 3).is_none());
+    }
+
+    #[test]
+    fn test_empty() {
+        let stencil = Stencil::new([]).unwrap();
+        let mut v: Vec<i32> = vec![];
+        let [] = stencil.index_many_mut(&mut v, 7);
+    }
+
+    #[test]
+    fn test_duplicate() {
+        assert!(Stencil::new([-1, 0, -1]).is_err());
+        assert!(Stencil::new([isize::MIN, isize::MAX]).is_ok());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Stencil with offsets [-1, 0, 1] at center 4 is out of bounds of slice with len 5"
+    )]
+    fn test_oob() {
+        let stencil = Stencil::new([-1, 0, 1]).unwrap();
+        let mut v = vec![1, 2, 3, 4, 5];
+        stencil.index_many_mut(&mut v, 4);
+    }
+
+    #[test]
+    fn test_2d_normal() {
+        let stencil = Stencil2d::new([(-1, -1), (1, 0), (0, 1)], 4).unwrap();
+        let mut grid: Vec<i32> = (0..12).collect();
+        let [a, b, c] = stencil.index_many_mut(&mut grid, 1, 1);
+        assert_eq!((*a, *b, *c), (0, 6, 9));
+        *a = 100;
+        assert_eq!(grid[0], 100);
+    }
+
+    #[test]
+    fn test_2d_bounds() {
+        let stencil = Stencil2d::new([(-1, 0), (0, 0), (1, 0), (0, -1), (0, 1)], 4).unwrap();
+        let grid: Vec<i32> = (0..12).collect();
+        for y in 0..3 {
+            for x in 0..4 {
+                let inner = (1..3).contains(&x) && y == 1;
+                assert_eq!(
+                    stencil.get_many(&grid, x, y).is_some(),
+                    inner,
+                    "({}, {})",
+                    x,
+                    y
+                );
+            }
+        }
+        assert!(stencil.get_many(&grid, 1, usize::MAX).is_none());
+    }
+
+    #[test]
+    fn test_2d_invalid() {
+        assert!(Stencil2d::new([(0, 0), (0, 0)], 4).is_err());
+        assert!(Stencil2d::new([(-2, 0), (2, 0)], 4).is_err());
+        assert!(Stencil2d::new([(-1, 0), (2, 0)], 4).is_ok());
+        assert!(Stencil2d::new([(0, isize::MAX)], 4).is_err());
+        assert!(Stencil2d::new([(0, 0)], 0).is_err());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Stencil with offsets [(0, 0), (1, 0)] at (3, 0) is out of bounds of grid with stride 4 and len 8"
+    )]
+    fn test_2d_oob() {
+        let stencil = Stencil2d::new([(0, 0), (1, 0)], 4).unwrap();
+        let mut grid = vec![0; 8];
+        stencil.index_many_mut(&mut grid, 3, 0);
+    }
+}
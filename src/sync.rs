@@ -0,0 +1,300 @@
+//! Functions for acquiring many locks of a slice at once, without risking a
+//! deadlock between callers.
+//!
+//! The locks are always acquired in ascending index order, which is a global
+//! lock order shared by all callers. The guards are returned in the order of
+//! the indices given by the caller.
+//!
+//! Like for [`Mutex::lock()`], a poisoned lock does not prevent acquiring it.
+//! If any of the acquired locks is poisoned, all guards are returned inside a
+//! [`PoisonError`].
+//!
+//! All functions panic if the indices are not valid for the slice of locks,
+//! including if they contain duplicates, since locking the same lock twice
+//! would deadlock.
+//!
+//! # Example
+//! ```
+//! use std::sync::Mutex;
+//! use index_many::generic::UnsortedIndices;
+//! use index_many::sync::lock_many;
+//!
+//! let shards = vec![Mutex::new(10), Mutex::new(20), Mutex::new(30)];
+//! let [mut from, mut to] = lock_many(&shards, UnsortedIndices([2, 0])).unwrap();
+//! *from -= 5;
+//! *to += 5;
+//! drop((from, to));
+//! assert_eq!(*shards[0].lock().unwrap(), 15);
+//! assert_eq!(*shards[2].lock().unwrap(), 25);
+//! ```
+
+use std::sync::{
+    LockResult, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+    TryLockError, TryLockResult,
+};
+
+use crate::generic::Indices;
+
+/// Acquires the locks at `indices` in ascending index order with `acquire`,
+/// and returns the guards in the order of `indices`.
+///
+/// If `acquire` would block for any lock, the locks acquired so far are
+/// released again.
+fn acquire_many<'a, L, G, I, F, const N: usize>(
+    locks: &'a [L],
+    indices: I,
+    mut acquire: F,
+) -> TryLockResult<[G; N]>
+where
+    I: Indices<N>,
+    F: FnMut(&'a L) -> TryLockResult<G>,
+{
+    if !indices.is_valid(locks.len()) {
+        indices.cause_invalid_panic(locks.len());
+    }
+
+    let indices = indices.to_raw_indices();
+    let mut order = [0; N];
+    for (i, pos) in order.iter_mut().enumerate() {
+        *pos = i;
+    }
+    order.sort_unstable_by_key(|&pos| indices[pos]);
+
+    let mut guards: [Option<G>; N] = [(); N].map(|_| None);
+    let mut poisoned = false;
+    for pos in order {
+        let guard = match acquire(&locks[indices[pos]]) {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(err)) => {
+                poisoned = true;
+                err.into_inner()
+            }
+            Err(TryLockError::WouldBlock) => return Err(TryLockError::WouldBlock),
+        };
+        guards[pos] = Some(guard);
+    }
+
+    let guards = guards.map(|guard| guard.expect("every lock has been acquired"));
+    if poisoned {
+        Err(TryLockError::Poisoned(PoisonError::new(guards)))
+    } else {
+        Ok(guards)
+    }
+}
+
+/// Converts the result of blocking acquisition, which never returns
+/// [`TryLockError::WouldBlock`].
+fn into_lock_result<G>(result: TryLockResult<G>) -> LockResult<G> {
+    match result {
+        Ok(guards) => Ok(guards),
+        Err(TryLockError::Poisoned(err)) => Err(err),
+        Err(TryLockError::WouldBlock) => unreachable!(),
+    }
+}
+
+/// Locks the mutexes at `indices`, blocking until all of them are acquired.
+///
+/// # Panics
+/// Panics if the indices are not valid for `mutexes`.
+pub fn lock_many<'a, T, I: Indices<N>, const N: usize>(
+    mutexes: &'a [Mutex<T>],
+    indices: I,
+) -> LockResult<[MutexGuard<'a, T>; N]> {
+    into_lock_result(acquire_many(mutexes, indices, |mutex| {
+        mutex.lock().map_err(TryLockError::from)
+    }))
+}
+
+/// Tries to lock the mutexes at `indices` without blocking.
+///
+/// Either all mutexes are acquired, or none are: if any of them is already
+/// locked, this returns [`TryLockError::WouldBlock`] and releases the ones
+/// acquired so far.
+///
+/// # Panics
+/// Panics if the indices are not valid for `mutexes`.
+pub fn try_lock_many<'a, T, I: Indices<N>, const N: usize>(
+    mutexes: &'a [Mutex<T>],
+    indices: I,
+) -> TryLockResult<[MutexGuard<'a, T>; N]> {
+    acquire_many(mutexes, indices, Mutex::try_lock)
+}
+
+/// Locks the `RwLock`s at `indices` with shared read access, blocking until
+/// all of them are acquired.
+///
+/// # Panics
+/// Panics if the indices are not valid for `locks`.
+pub fn read_many<'a, T, I: Indices<N>, const N: usize>(
+    locks: &'a [RwLock<T>],
+    indices: I,
+) -> LockResult<[RwLockReadGuard<'a, T>; N]> {
+    into_lock_result(acquire_many(locks, indices, |lock| {
+        lock.read().map_err(TryLockError::from)
+    }))
+}
+
+/// Tries to lock the `RwLock`s at `indices` with shared read access, without
+/// blocking. Either all locks are acquired, or none are.
+///
+/// # Panics
+/// Panics if the indices are not valid for `locks`.
+pub fn try_read_many<'a, T, I: Indices<N>, const N: usize>(
+    locks: &'a [RwLock<T>],
+    indices: I,
+) -> TryLockResult<[RwLockReadGuard<'a, T>; N]> {
+    acquire_many(locks, indices, RwLock::try_read)
+}
+
+/// Locks the `RwLock`s at `indices` with exclusive write access, blocking
+/// until all of them are acquired.
+///
+/// # Panics
+/// Panics if the indices are not valid for `locks`.
+pub fn write_many<'a, T, I: Indices<N>, const N: usize>(
+    locks: &'a [RwLock<T>],
+    indices: I,
+) -> LockResult<[RwLockWriteGuard<'a, T>; N]> {
+    into_lock_result(acquire_many(locks, indices, |lock| {
+        lock.write().map_err(TryLockError::from)
+    }))
+}
+
+/// Tries to lock the `RwLock`s at `indices` with exclusive write access,
+/// without blocking. Either all locks are acquired, or none are.
+///
+/// # Panics
+/// Panics if the indices are not valid for `locks`.
+pub fn try_write_many<'a, T, I: Indices<N>, const N: usize>(
+    locks: &'a [RwLock<T>],
+    indices: I,
+) -> TryLockResult<[RwLockWriteGuard<'a, T>; N]> {
+    acquire_many(locks, indices, RwLock::try_write)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generic::UnsortedIndices;
+    use std::thread;
+
+    #[test]
+    fn test_lock_caller_order() {
+        let mutexes: Vec<_> = (0..5).map(Mutex::new).collect();
+        let [a, b, c] = lock_many(&mutexes, UnsortedIndices([4, 0, 2])).unwrap();
+        assert_eq!((*a, *b, *c), (4, 0, 2));
+        assert!(mutexes[1].try_lock().is_ok());
+        assert!(mutexes[4].try_lock().is_err());
+    }
+
+    #[test]
+    fn test_try_lock() {
+        let mutexes: Vec<_> = (0..3).map(Mutex::new).collect();
+        let held = mutexes[2].lock().unwrap();
+        assert!(matches!(
+            try_lock_many(&mutexes, UnsortedIndices([1, 0, 2])),
+            Err(TryLockError::WouldBlock)
+        ));
+        // The locks acquired before blocking have been released.
+        assert!(mutexes[0].try_lock().is_ok());
+        assert!(mutexes[1].try_lock().is_ok());
+        drop(held);
+
+        let [a, b] = try_lock_many(&mutexes, UnsortedIndices([2, 1])).unwrap();
+        assert_eq!((*a, *b), (2, 1));
+    }
+
+    #[test]
+    fn test_poisoned() {
+        let mutexes: Vec<_> = (0..3).map(Mutex::new).collect();
+        thread::scope(|s| {
+            let poison = s.spawn(|| {
+                let _guard = mutexes[1].lock();
+                panic!("poison");
+            });
+            assert!(poison.join().is_err());
+        });
+
+        let err = lock_many(&mutexes, UnsortedIndices([1, 2])).unwrap_err();
+        let [mut a, b] = err.into_inner();
+        *a += 10;
+        assert_eq!((*a, *b), (11, 2));
+        drop((a, b));
+
+        assert!(matches!(
+            try_lock_many(&mutexes, [0, 1]),
+            Err(TryLockError::Poisoned(_))
+        ));
+        assert!(lock_many(&mutexes, [0, 2]).is_ok());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 1 appears more than once (indices [1, 0, 1], position 0 and 2)"
+    )]
+    fn test_duplicate() {
+        let mutexes: Vec<_> = (0..3).map(Mutex::new).collect();
+        let _ = lock_many(&mutexes, UnsortedIndices([1, 0, 1]));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 3 is out of bounds of slice with len 3 (indices [0, 3], position 1)"
+    )]
+    fn test_oob() {
+        let locks: Vec<_> = (0..3).map(RwLock::new).collect();
+        let _ = read_many(&locks, [0, 3]);
+    }
+
+    #[test]
+    fn test_rwlock() {
+        let locks: Vec<_> = (0..4).map(RwLock::new).collect();
+        {
+            let [a, b] = read_many(&locks, UnsortedIndices([3, 1])).unwrap();
+            let [c, d] = try_read_many(&locks, [1, 2]).unwrap();
+            assert_eq!((*a, *b, *c, *d), (3, 1, 1, 2));
+            assert!(matches!(
+                try_write_many(&locks, [0, 1]),
+                Err(TryLockError::WouldBlock)
+            ));
+            assert!(locks[0].try_write().is_ok());
+        }
+
+        let [mut a, mut b] = write_many(&locks, UnsortedIndices([2, 0])).unwrap();
+        std::mem::swap(&mut *a, &mut *b);
+        assert!(matches!(
+            try_read_many(&locks, [0, 1]),
+            Err(TryLockError::WouldBlock)
+        ));
+        drop((a, b));
+        assert_eq!(*locks[0].read().unwrap(), 2);
+        assert_eq!(*locks[2].read().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_no_deadlock() {
+        let mutexes: Vec<_> = (0..3).map(|_| Mutex::new(0)).collect();
+        let orders = [
+            UnsortedIndices([0, 1, 2]),
+            UnsortedIndices([2, 1, 0]),
+            UnsortedIndices([1, 2, 0]),
+            UnsortedIndices([2, 0, 1]),
+        ];
+        thread::scope(|s| {
+            for indices in orders {
+                let mutexes = &mutexes;
+                s.spawn(move || {
+                    for _ in 0..1000 {
+                        let [mut a, mut b, mut c] = lock_many(mutexes, indices).unwrap();
+                        *a += 1;
+                        *b += 1;
+                        *c += 1;
+                    }
+                });
+            }
+        });
+        for mutex in &mutexes {
+            assert_eq!(*mutex.lock().unwrap(), 4000);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Fixtures shared by the tests of several modules.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// A value that counts how often values sharing its counter were dropped.
+pub(crate) struct DropCounter {
+    pub(crate) value: i32,
+    drops: Rc<Cell<usize>>,
+    panics: bool,
+}
+
+impl DropCounter {
+    pub(crate) fn new(value: i32, drops: &Rc<Cell<usize>>) -> Self {
+        DropCounter {
+            value,
+            drops: drops.clone(),
+            panics: false,
+        }
+    }
+
+    /// Like [`DropCounter::new()`], but its `Drop` impl panics after
+    /// counting the drop.
+    pub(crate) fn panicking(value: i32, drops: &Rc<Cell<usize>>) -> Self {
+        let mut counter = DropCounter::new(value, drops);
+        counter.panics = true;
+        counter
+    }
+}
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+        if self.panics {
+            panic!("DropCounter with value {} panics", self.value);
+        }
+    }
+}
+
+/// Returns a `DropCounter` for each of `values`, sharing the counter `drops`.
+pub(crate) fn counters<const M: usize>(
+    values: [i32; M],
+    drops: &Rc<Cell<usize>>,
+) -> [DropCounter; M] {
+    values.map(|value| DropCounter::new(value, drops))
+}
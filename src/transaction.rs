@@ -0,0 +1,265 @@
+//! Functions for updating many elements of a slice, with the updates rolled
+//! back unless they are explicitly committed.
+//!
+//! A [`Transaction`] saves the values of the selected elements when it is
+//! created, and writes them back when it is dropped without calling
+//! [`Transaction::commit()`]. This includes early returns from `?` and
+//! unwinding panics.
+//!
+//! # Example
+//! ```
+//! use index_many::transaction::begin_many_mut;
+//!
+//! fn transfer(accounts: &mut [u32], from: usize, to: usize, amount: u32) -> Result<(), ()> {
+//!     let mut tx = begin_many_mut(accounts, [from, to]);
+//!     let [from, to] = tx.get_mut();
+//!     *to += amount;
+//!     *from = from.checked_sub(amount).ok_or(())?;
+//!     tx.commit();
+//!     Ok(())
+//! }
+//!
+//! let mut accounts = vec![100, 50];
+//! assert!(transfer(&mut accounts, 0, 1, 70).is_ok());
+//! assert!(transfer(&mut accounts, 0, 1, 70).is_err());
+//! assert_eq!(accounts, vec![30, 120]);
+//! ```
+
+use std::mem;
+
+use crate::generic::Indices;
+
+/// Mutable references to many elements of a slice, together with values
+/// that are written back to them on drop unless the transaction is
+/// committed.
+pub struct Transaction<'a, T, const N: usize> {
+    refs: [&'a mut T; N],
+    saved: Option<[T; N]>,
+}
+
+impl<'a, T, const N: usize> Transaction<'a, T, N> {
+    pub fn get(&self) -> [&T; N] {
+        self.refs.each_ref().map(|r| &**r)
+    }
+
+    pub fn get_mut(&mut self) -> [&mut T; N] {
+        self.refs.each_mut().map(|r| &mut **r)
+    }
+
+    /// Keeps the updated values, and returns the saved ones.
+    pub fn commit(mut self) -> [T; N] {
+        self.saved
+            .take()
+            .expect("saved values are present until commit or drop")
+    }
+
+    /// Writes the saved values back. This is the same as dropping the
+    /// transaction.
+    pub fn rollback(self) {}
+}
+
+impl<T, const N: usize> Drop for Transaction<'_, T, N> {
+    fn drop(&mut self) {
+        if let Some(saved) = self.saved.take() {
+            // NB: Restore every slot before dropping any of the modified
+            // values, so that a panicking `Drop` impl cannot leave some of
+            // the slots modified.
+            let mut refs = self.refs.iter_mut();
+            let modified = saved.map(|value| {
+                let r = refs.next().expect("one saved value per slot");
+                mem::replace(&mut **r, value)
+            });
+            drop(modified);
+        }
+    }
+}
+
+/// Starts a transaction on the elements at `indices`, saving clones of
+/// their current values, if the indices are valid for `slice`.
+pub fn try_begin_many_mut<T: Clone, I: Indices<N>, const N: usize>(
+    slice: &mut [T],
+    indices: I,
+) -> Option<Transaction<'_, T, N>> {
+    let refs = crate::generic::get_many_mut(slice, indices)?;
+    let saved = refs.each_ref().map(|r| (**r).clone());
+    Some(Transaction {
+        refs,
+        saved: Some(saved),
+    })
+}
+
+/// Starts a transaction on the elements at `indices`, saving clones of
+/// their current values.
+///
+/// # Panics
+/// Panics if the indices are not valid for `slice`.
+pub fn begin_many_mut<T: Clone, I: Indices<N>, const N: usize>(
+    slice: &mut [T],
+    indices: I,
+) -> Transaction<'_, T, N> {
+    let len = slice.len();
+    match try_begin_many_mut(slice, indices) {
+        Some(tx) => tx,
+        None => indices.cause_invalid_panic(len),
+    }
+}
+
+/// Starts a transaction on the elements at `indices` by swapping `values`
+/// into them, and saving their previous values, if the indices are valid
+/// for `slice`. Otherwise, returns the values back.
+///
+/// Unlike [`try_begin_many_mut()`], this does not need `T: Clone`.
+pub fn try_begin_many_swap<T, I: Indices<N>, const N: usize>(
+    slice: &mut [T],
+    indices: I,
+    values: [T; N],
+) -> Result<Transaction<'_, T, N>, [T; N]> {
+    let mut refs = match crate::generic::get_many_mut(slice, indices) {
+        Some(refs) => refs,
+        None => return Err(values),
+    };
+    let mut saved = values;
+    for (r, value) in refs.iter_mut().zip(&mut saved) {
+        std::mem::swap(*r, value);
+    }
+    Ok(Transaction {
+        refs,
+        saved: Some(saved),
+    })
+}
+
+/// Starts a transaction on the elements at `indices` by swapping `values`
+/// into them, and saving their previous values.
+///
+/// Unlike [`begin_many_mut()`], this does not need `T: Clone`.
+///
+/// # Panics
+/// Panics if the indices are not valid for `slice`.
+///
+/// # Example
+/// ```
+/// use index_many::transaction::begin_many_swap;
+///
+/// let mut v = vec![String::from("a"), String::from("b")];
+/// let mut tx = begin_many_swap(&mut v, [0, 1], [String::new(), String::new()]);
+/// let [a, b] = tx.get_mut();
+/// a.push_str("new");
+/// b.push_str("new");
+/// drop(tx);
+/// assert_eq!(v, vec!["a", "b"]);
+/// ```
+pub fn begin_many_swap<T, I: Indices<N>, const N: usize>(
+    slice: &mut [T],
+    indices: I,
+    values: [T; N],
+) -> Transaction<'_, T, N> {
+    let len = slice.len();
+    match try_begin_many_swap(slice, indices, values) {
+        Ok(tx) => tx,
+        Err(_) => indices.cause_invalid_panic(len),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generic::UnsortedIndices;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[test]
+    fn test_rollback() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let mut tx = begin_many_mut(&mut v, UnsortedIndices([4, 0]));
+        let [a, b] = tx.get_mut();
+        *a = 50;
+        *b = 10;
+        assert_eq!(tx.get(), [&50, &10]);
+        tx.rollback();
+        assert_eq!(v, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_commit() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let mut tx = begin_many_mut(&mut v, [1, 3]);
+        let [a, b] = tx.get_mut();
+        std::mem::swap(a, b);
+        assert_eq!(tx.commit(), [2, 4]);
+        assert_eq!(v, vec![1, 4, 3, 2, 5]);
+    }
+
+    #[test]
+    fn test_rollback_on_panic() {
+        let mut v = vec![String::from("a"), String::from("b")];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let mut tx = begin_many_mut(&mut v, [0, 1]);
+            let [a, b] = tx.get_mut();
+            a.push('!');
+            b.clear();
+            panic!("failed halfway");
+        }));
+        assert!(result.is_err());
+        assert_eq!(v, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_rollback_panicking_drop() {
+        #[derive(Debug, PartialEq)]
+        struct PanicOnDrop(i32);
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                if self.0 < 0 {
+                    panic!("negative value dropped");
+                }
+            }
+        }
+
+        let mut v = vec![PanicOnDrop(1), PanicOnDrop(2), PanicOnDrop(3)];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let tx = begin_many_swap(&mut v, [0, 2], [PanicOnDrop(-1), PanicOnDrop(30)]);
+            tx.rollback();
+        }));
+        assert!(result.is_err());
+        assert_eq!(v, vec![PanicOnDrop(1), PanicOnDrop(2), PanicOnDrop(3)]);
+    }
+
+    #[test]
+    fn test_swap() {
+        struct NoClone(i32);
+
+        let mut v = vec![NoClone(1), NoClone(2), NoClone(3)];
+        let mut tx = begin_many_swap(&mut v, [0, 2], [NoClone(10), NoClone(30)]);
+        let [a, b] = tx.get_mut();
+        a.0 += 1;
+        b.0 += 1;
+        drop(tx);
+        assert_eq!(v.iter().map(|x| x.0).collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let tx = begin_many_swap(&mut v, [1], [NoClone(20)]);
+        let [old] = tx.commit();
+        assert_eq!(old.0, 2);
+        assert_eq!(v.iter().map(|x| x.0).collect::<Vec<_>>(), vec![1, 20, 3]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut v = vec![1, 2, 3];
+        assert!(try_begin_many_mut(&mut v, [0, 3]).is_none());
+        assert!(try_begin_many_mut(&mut v, UnsortedIndices([1, 1])).is_none());
+        assert_eq!(
+            try_begin_many_swap(&mut v, [2, 1], [7, 8]).err(),
+            Some([7, 8])
+        );
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 3 is out of bounds of slice with len 3 (indices [0, 3], position 1)"
+    )]
+    fn test_oob() {
+        let mut v = vec![1, 2, 3];
+        begin_many_swap(&mut v, [0, 3], [7, 8]);
+    }
+}
@@ -0,0 +1,343 @@
+//! Functions for initializing many slots of a `[MaybeUninit<T>]` buffer at once.
+//!
+//! The indices are validated the same way as for [`crate::generic::get_many_mut()`].
+//!
+//! # Partial initialization
+//! [`write_many()`] moves all values into the buffer without calling any user
+//! code in between, so it either initializes all `N` slots or none of them.
+//!
+//! [`get_many_uninit_mut()`] returns an [`UninitMany`] guard instead, that
+//! keeps track of which of its slots have been written so far. If the guard is
+//! dropped before [`UninitMany::finish()`] succeeds, for example because a
+//! panic interrupted the fill, it drops all values it has written, even if
+//! the `Drop` impl of one of them panics. This means
+//! that unless `finish` returned the initialized references, all of the slots
+//! are uninitialized again afterwards, and nothing is leaked or dropped twice.
+
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ptr;
+
+use crate::generic::Indices;
+
+/// Writes each value into the slot at the corresponding index, and returns
+/// references to the now initialized values.
+///
+/// Any values already in the slots are overwritten without being dropped.
+///
+/// # Panics
+/// Panics if the indices are invalid for `slice`.
+///
+/// # Example
+/// ```
+/// use std::mem::MaybeUninit;
+/// use index_many::generic::UnsortedIndices;
+/// use index_many::uninit::write_many;
+///
+/// let mut buf = [(); 4].map(|()| MaybeUninit::<String>::uninit());
+/// let [a, b] = write_many(&mut buf, UnsortedIndices([3, 0]), ["a".into(), "b".into()]);
+/// a.push('!');
+/// assert_eq!((a.as_str(), b.as_str()), ("a!", "b"));
+/// # unsafe {
+/// #     buf[0].assume_init_drop();
+/// #     buf[3].assume_init_drop();
+/// # }
+/// ```
+pub fn write_many<T, I: Indices<N>, const N: usize>(
+    slice: &mut [MaybeUninit<T>],
+    indices: I,
+    values: [T; N],
+) -> [&mut T; N] {
+    let len = slice.len();
+    match try_write_many(slice, indices, values) {
+        Ok(s) => s,
+        Err(_) => indices.cause_invalid_panic(len),
+    }
+}
+
+/// Like [`write_many()`], but returns the values back if the indices are invalid.
+pub fn try_write_many<T, I: Indices<N>, const N: usize>(
+    slice: &mut [MaybeUninit<T>],
+    indices: I,
+    values: [T; N],
+) -> Result<[&mut T; N], [T; N]> {
+    let slots = match crate::generic::get_many_mut(slice, indices) {
+        Some(slots) => slots,
+        None => return Err(values),
+    };
+
+    let values = ManuallyDrop::new(values);
+    let mut i = 0;
+    Ok(slots.map(|slot| {
+        // SAFETY: Each value is read exactly once, and `values` is not
+        // dropped afterwards.
+        let value = unsafe { ptr::read(&values[i]) };
+        i += 1;
+        slot.write(value)
+    }))
+}
+
+/// Returns a guard for initializing the slots at `indices` one by one,
+/// if the indices are valid for `slice`.
+///
+/// See the [module docs](self) for what happens if the guard is dropped
+/// before all slots are initialized.
+pub fn get_many_uninit_mut<T, I: Indices<N>, const N: usize>(
+    slice: &mut [MaybeUninit<T>],
+    indices: I,
+) -> Option<UninitMany<'_, T, N>> {
+    let slots = crate::generic::get_many_mut(slice, indices)?;
+    Some(UninitMany {
+        slots,
+        init: [false; N],
+    })
+}
+
+/// Like [`get_many_uninit_mut()`], but panics if the indices are invalid.
+pub fn index_many_uninit_mut<T, I: Indices<N>, const N: usize>(
+    slice: &mut [MaybeUninit<T>],
+    indices: I,
+) -> UninitMany<'_, T, N> {
+    let len = slice.len();
+    match get_many_uninit_mut(slice, indices) {
+        Some(s) => s,
+        None => indices.cause_invalid_panic(len),
+    }
+}
+
+/// A guard over `N` distinct slots of a `[MaybeUninit<T>]` buffer, that tracks
+/// which of them have been initialized.
+///
+/// The slots are addressed by their position `many_idx` in the indices
+/// array the guard was created with.
+pub struct UninitMany<'a, T, const N: usize> {
+    slots: [&'a mut MaybeUninit<T>; N],
+    init: [bool; N],
+}
+
+impl<'a, T, const N: usize> UninitMany<'a, T, N> {
+    /// Writes `value` into the slot at `many_idx`, and returns a reference to it.
+    ///
+    /// If the slot was already initialized, its old value gets dropped first.
+    ///
+    /// # Panics
+    /// Panics if `many_idx >= N`.
+    pub fn write(&mut self, many_idx: usize, value: T) -> &mut T {
+        if self.init[many_idx] {
+            // NB: Mark the slot as uninitialized before dropping the old value,
+            // so that it does not get dropped again if its `Drop` impl panics.
+            self.init[many_idx] = false;
+            // SAFETY: The slot was initialized.
+            unsafe { self.slots[many_idx].assume_init_drop() };
+        }
+        self.init[many_idx] = true;
+        self.slots[many_idx].write(value)
+    }
+
+    /// Returns whether the slot at `many_idx` is initialized.
+    ///
+    /// # Panics
+    /// Panics if `many_idx >= N`.
+    pub fn is_init(&self, many_idx: usize) -> bool {
+        self.init[many_idx]
+    }
+
+    /// Returns whether all slots are initialized.
+    pub fn is_all_init(&self) -> bool {
+        self.init.iter().all(|&init| init)
+    }
+
+    /// Returns a reference to the value at `many_idx`, if it is initialized.
+    ///
+    /// # Panics
+    /// Panics if `many_idx >= N`.
+    pub fn get_mut(&mut self, many_idx: usize) -> Option<&mut T> {
+        if self.init[many_idx] {
+            // SAFETY: The slot is initialized.
+            unsafe { Some(self.slots[many_idx].assume_init_mut()) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns references to all values if every slot is initialized,
+    /// and gives back the guard otherwise.
+    ///
+    /// On success, the values are no longer owned by the guard, and will not
+    /// be dropped by this crate.
+    pub fn finish(self) -> Result<[&'a mut T; N], Self> {
+        if !self.is_all_init() {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never used or dropped again.
+        let slots = unsafe { ptr::read(&this.slots) };
+        // SAFETY: We checked that all slots are initialized.
+        Ok(slots.map(|slot| unsafe { slot.assume_init_mut() }))
+    }
+}
+
+impl<'a, T, const N: usize> UninitMany<'a, T, N> {
+    /// Drops all initialized values, and marks their slots as uninitialized.
+    fn drop_init(&mut self) {
+        for i in 0..N {
+            if self.init[i] {
+                self.init[i] = false;
+                let slot: *mut MaybeUninit<T> = &mut *self.slots[i];
+                // NB: If the `Drop` impl of this value panics, `rest` drops
+                // the values in the following slots.
+                let rest = DropRest(self);
+                // SAFETY: The slot was initialized, and is marked as
+                // uninitialized now, so it is not dropped again by `rest`.
+                unsafe { (*slot).assume_init_drop() };
+                mem::forget(rest);
+            }
+        }
+    }
+}
+
+/// Drops the remaining initialized values of an [`UninitMany`] when dropped.
+struct DropRest<'r, 'a, T, const N: usize>(&'r mut UninitMany<'a, T, N>);
+
+impl<T, const N: usize> Drop for DropRest<'_, '_, T, N> {
+    fn drop(&mut self) {
+        self.0.drop_init();
+    }
+}
+
+impl<'a, T, const N: usize> Drop for UninitMany<'a, T, N> {
+    fn drop(&mut self) {
+        self.drop_init();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    use crate::generic::UnsortedIndices;
+    use crate::test_util::DropCounter;
+
+    fn uninit<T, const N: usize>() -> [MaybeUninit<T>; N] {
+        [(); N].map(|()| MaybeUninit::uninit())
+    }
+
+    #[test]
+    fn test_write_normal() {
+        let mut buf = uninit::<i32, 5>();
+        let [a, b, c] = write_many(&mut buf, [0, 2, 4], [1, 3, 5]);
+        *a += 10;
+        assert_eq!((*a, *b, *c), (11, 3, 5));
+        unsafe {
+            assert_eq!(buf[0].assume_init(), 11);
+            assert_eq!(buf[2].assume_init(), 3);
+            assert_eq!(buf[4].assume_init(), 5);
+        }
+    }
+
+    #[test]
+    fn test_write_permutation() {
+        let perm = [2, 0, 3, 1];
+        let mut buf = uninit::<usize, 4>();
+        write_many(&mut buf, UnsortedIndices(perm), [0, 1, 2, 3]);
+        let inverse = buf.map(|x| unsafe { x.assume_init() });
+        assert_eq!(inverse, [1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn test_try_write_invalid() {
+        let mut buf = uninit::<i32, 3>();
+        assert_eq!(
+            try_write_many(&mut buf, [0, 3], [1, 2]).unwrap_err(),
+            [1, 2]
+        );
+        assert_eq!(
+            try_write_many(&mut buf, [1, 1], [1, 2]).unwrap_err(),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 3 appears more than once (indices [3, 0, 3], position 0 and 2)"
+    )]
+    fn test_write_duplicate() {
+        let mut buf = uninit::<i32, 5>();
+        write_many(&mut buf, UnsortedIndices([3, 0, 3]), [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_guard_finish() {
+        let mut buf = uninit::<String, 4>();
+        let mut guard = get_many_uninit_mut(&mut buf, UnsortedIndices([3, 1])).unwrap();
+        guard.write(1, "b".to_owned());
+        assert!(guard.is_init(1));
+        assert!(!guard.is_init(0));
+        assert!(guard.get_mut(0).is_none());
+        let mut guard = guard.finish().unwrap_err();
+        guard.write(0, "a".to_owned());
+        guard.get_mut(0).unwrap().push('!');
+        let [a, b] = guard.finish().ok().unwrap();
+        assert_eq!((a.as_str(), b.as_str()), ("a!", "b"));
+        unsafe {
+            assert_eq!(buf[3].assume_init_read(), "a!");
+            assert_eq!(buf[1].assume_init_read(), "b");
+        }
+    }
+
+    #[test]
+    fn test_guard_invalid() {
+        let mut buf = uninit::<i32, 3>();
+        assert!(get_many_uninit_mut(&mut buf, [2, 1]).is_none());
+        assert!(get_many_uninit_mut(&mut buf, [1, 3]).is_none());
+    }
+
+    #[test]
+    fn test_guard_overwrite_drops() {
+        let drops = Rc::new(Cell::new(0));
+        let mut buf = uninit::<DropCounter, 2>();
+        let mut guard = index_many_uninit_mut(&mut buf, [0]);
+        guard.write(0, DropCounter::new(0, &drops));
+        guard.write(0, DropCounter::new(0, &drops));
+        assert_eq!(drops.get(), 1);
+        let [a] = guard.finish().ok().unwrap();
+        unsafe { ptr::drop_in_place(a) };
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn test_guard_panicking_drop() {
+        let drops = Rc::new(Cell::new(0));
+        let mut buf = uninit::<DropCounter, 4>();
+
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            let mut guard = index_many_uninit_mut(&mut buf, [0, 1, 2, 3]);
+            guard.write(0, DropCounter::new(0, &drops));
+            guard.write(1, DropCounter::panicking(1, &drops));
+            guard.write(3, DropCounter::new(3, &drops));
+        }));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn test_guard_panic_during_fill() {
+        let drops = Rc::new(Cell::new(0));
+        let mut buf = uninit::<DropCounter, 5>();
+
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            let mut guard = index_many_uninit_mut(&mut buf, UnsortedIndices([4, 0, 2]));
+            for i in 0..3 {
+                if i == 2 {
+                    panic!("interrupted");
+                }
+                guard.write(i, DropCounter::new(0, &drops));
+            }
+        }));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 2);
+    }
+}
@@ -0,0 +1,531 @@
+//! Functions for removing and inserting many elements of a `Vec` at once.
+//!
+//! Calling `Vec::remove` or `Vec::insert` once per element shifts the tail
+//! of the vector each time, which is `O(N * len)`. The functions here shift
+//! every element at most once, which is `O(N + len)`.
+//!
+//! None of them call user code while the vector is being rearranged, so a
+//! panicking `Drop` impl can neither cause a double drop nor a leak.
+
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+
+use crate::generic::{Indices, PresortedIndices};
+use crate::simple::check_indices_valid;
+
+/// Moves the elements at the sorted positions `indices` out of `vec`.
+///
+/// # Safety
+/// `indices` has to be sorted, unique and in bounds of `vec`.
+unsafe fn remove_many_raw<T>(vec: &mut Vec<T>, indices: &[usize], out: *mut T) {
+    let len = vec.len();
+    let base = vec.as_mut_ptr();
+
+    for (i, &idx) in indices.iter().enumerate() {
+        ptr::copy_nonoverlapping(base.add(idx), out.add(i), 1);
+    }
+
+    // Close each gap by moving the run of elements that follows it
+    // to the front, past all gaps seen so far.
+    for (i, &idx) in indices.iter().enumerate() {
+        let src = idx + 1;
+        let end = indices.get(i + 1).copied().unwrap_or(len);
+        ptr::copy(base.add(src), base.add(src - (i + 1)), end - src);
+    }
+
+    vec.set_len(len - indices.len());
+}
+
+/// Drops the elements of a vector buffer that lie between the sorted
+/// positions `taken`, which have already been moved out.
+///
+/// The elements are dropped when the guard is dropped. If the `Drop` impl of
+/// one of them panics, the remaining elements are still dropped.
+struct DropGaps<T, const N: usize> {
+    base: *mut T,
+    len: usize,
+    taken: [usize; N],
+    next_gap: usize,
+}
+
+impl<T, const N: usize> Drop for DropGaps<T, N> {
+    fn drop(&mut self) {
+        while self.next_gap <= N {
+            let gap = self.next_gap;
+            self.next_gap += 1;
+
+            let start = if gap == 0 { 0 } else { self.taken[gap - 1] + 1 };
+            let end = self.taken.get(gap).copied().unwrap_or(self.len);
+
+            // NB: Dropping a slice continues with its next element if one of
+            // them panics, and `rest` takes care of the gaps after this one.
+            let rest = DropGaps { ..*self };
+            // SAFETY: The elements in the gap are initialized and not used
+            // afterwards, as guaranteed by whoever created the guard.
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    self.base.add(start),
+                    end - start,
+                ));
+            }
+            mem::forget(rest);
+        }
+    }
+}
+
+/// Moves the elements out of `values` into `vec`, such that each of them
+/// ends up at its given position of the resulting vector.
+///
+/// # Safety
+/// `positions` has to be sorted, unique and in bounds of the resulting
+/// vector, `vec` has to have capacity for `positions.len()` more elements, and
+/// `values` has to point to `positions.len()` initialized elements that are
+/// no longer used afterwards.
+unsafe fn insert_many_raw<T>(vec: &mut Vec<T>, positions: &[usize], values: *const T) {
+    let old_len = vec.len();
+    let new_len = old_len + positions.len();
+    let base = vec.as_mut_ptr();
+
+    // Go back to front, so that every run of old elements gets moved
+    // exactly once, into the gap behind its inserted element.
+    let mut src_end = old_len;
+    for (i, &pos) in positions.iter().enumerate().rev() {
+        let next = positions.get(i + 1).copied().unwrap_or(new_len);
+        let run = next - pos - 1;
+        src_end -= run;
+        ptr::copy(base.add(src_end), base.add(pos + 1), run);
+        ptr::copy_nonoverlapping(values.add(i), base.add(pos), 1);
+    }
+    debug_assert_eq!(src_end, positions.first().copied().unwrap_or(old_len));
+
+    vec.set_len(new_len);
+}
+
+/// Removes the elements at `indices` and returns them, shifting all
+/// remaining elements to the front.
+///
+/// # Panics
+/// Panics if any index is out of bounds.
+///
+/// # Example
+/// ```
+/// use index_many::generic::PresortedIndices;
+/// use index_many::vec::remove_many;
+///
+/// let mut v = vec![1, 2, 3, 4, 5];
+/// let removed = remove_many(&mut v, PresortedIndices::new([0, 2, 3]).unwrap());
+/// assert_eq!(removed, [1, 3, 4]);
+/// assert_eq!(v, [2, 5]);
+/// ```
+pub fn remove_many<T, const N: usize>(vec: &mut Vec<T>, indices: PresortedIndices<N>) -> [T; N] {
+    if !indices.is_valid(vec.len()) {
+        indices.cause_invalid_panic(vec.len())
+    }
+
+    let mut out: MaybeUninit<[T; N]> = MaybeUninit::uninit();
+    // SAFETY: `PresortedIndices` is sorted and unique, and we checked that
+    // the last index is in bounds. `remove_many_raw` initializes all
+    // `N` elements of `out`.
+    unsafe {
+        remove_many_raw(vec, &*indices, out.as_mut_ptr() as *mut T);
+        out.assume_init()
+    }
+}
+
+/// Inserts each value at its paired position, shifting all following
+/// elements to the back.
+///
+/// The positions refer to the resulting vector, so they have to be sorted,
+/// unique and less than `vec.len() + N`. This makes `insert_many` the
+/// inverse of [`remove_many`].
+///
+/// # Panics
+/// Panics if the positions are not sorted, not unique or out of bounds.
+///
+/// # Example
+/// ```
+/// use index_many::vec::insert_many;
+///
+/// let mut v = vec![2, 5];
+/// insert_many(&mut v, [(0, 1), (2, 3), (3, 4)]);
+/// assert_eq!(v, [1, 2, 3, 4, 5]);
+/// ```
+pub fn insert_many<T, const N: usize>(vec: &mut Vec<T>, values: [(usize, T); N]) {
+    let mut positions = [0; N];
+    for (pos, (idx, _)) in positions.iter_mut().zip(&values) {
+        *pos = *idx;
+    }
+
+    let new_len = vec.len().saturating_add(N);
+    if !check_indices_valid(&positions, new_len) {
+        crate::sorted_bound_check_failed(&positions, new_len)
+    }
+
+    vec.reserve(N);
+
+    let values = values.map(|(_, value)| value);
+    let values = MaybeUninit::new(values);
+    // SAFETY: We checked the positions above and reserved enough space.
+    // `values` is wrapped in `MaybeUninit`, so its elements are not
+    // dropped after being moved into `vec`.
+    unsafe {
+        insert_many_raw(vec, &positions, values.as_ptr() as *const T);
+    }
+}
+
+/// Removes the elements at `indices` and returns them, shifting all
+/// remaining elements to the front.
+///
+/// This is the dynamic-length version of [`remove_many`].
+///
+/// # Panics
+/// Panics if the indices are not sorted, not unique or out of bounds.
+pub fn remove_many_dyn<T>(vec: &mut Vec<T>, indices: &[usize]) -> Vec<T> {
+    if !check_indices_valid(indices, vec.len()) {
+        crate::sorted_bound_check_failed(indices, vec.len())
+    }
+
+    let mut out = Vec::with_capacity(indices.len());
+    // SAFETY: We checked the indices above, and `out` has room for
+    // `indices.len()` elements, all of which `remove_many_raw` initializes.
+    unsafe {
+        remove_many_raw(vec, indices, out.as_mut_ptr());
+        out.set_len(indices.len());
+    }
+    out
+}
+
+/// Inserts each value at its paired position, shifting all following
+/// elements to the back.
+///
+/// This is the dynamic-length version of [`insert_many`].
+///
+/// # Panics
+/// Panics if the positions are not sorted, not unique or out of bounds.
+pub fn insert_many_dyn<T>(vec: &mut Vec<T>, values: Vec<(usize, T)>) {
+    let positions: Vec<usize> = values.iter().map(|&(idx, _)| idx).collect();
+
+    let new_len = vec.len().saturating_add(positions.len());
+    if !check_indices_valid(&positions, new_len) {
+        crate::sorted_bound_check_failed(&positions, new_len)
+    }
+
+    vec.reserve(positions.len());
+
+    let mut values: Vec<T> = values.into_iter().map(|(_, value)| value).collect();
+    // SAFETY: We checked the positions above and reserved enough space.
+    // Setting the length of `values` to zero hands ownership of its
+    // elements over to `vec`.
+    unsafe {
+        values.set_len(0);
+        insert_many_raw(vec, &positions, values.as_ptr());
+    }
+}
+
+/// Moves the elements at `indices` out of `vec`, and drops all other elements.
+///
+/// This is the `Vec` version of [`crate::array::take_many()`].
+///
+/// # Panics
+/// Panics if the indices are invalid for `vec`.
+pub fn take_many<T, I: Indices<N>, const N: usize>(mut vec: Vec<T>, indices: I) -> [T; N] {
+    if !indices.is_valid(vec.len()) {
+        indices.cause_invalid_panic(vec.len())
+    }
+
+    let len = vec.len();
+    let mut taken = indices.to_raw_indices();
+    let base = vec.as_mut_ptr();
+
+    // SAFETY: We checked that the indices are unique and in bounds. After
+    // setting the length to zero, `vec` only frees its buffer, so every
+    // element is either read out or dropped by `gaps`, exactly once.
+    unsafe {
+        vec.set_len(0);
+        let values = taken.map(|idx| ptr::read(base.add(idx)));
+
+        taken.sort_unstable();
+        let gaps = DropGaps {
+            base,
+            len,
+            taken,
+            next_gap: 0,
+        };
+        // NB: The remaining elements have to be dropped while `values` is
+        // still a local, so that it gets dropped as well if one of them panics.
+        drop(gaps);
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::Cell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    use crate::generic::UnsortedIndices;
+    use crate::test_util::{counters, DropCounter};
+
+    fn values(v: &[DropCounter]) -> Vec<i32> {
+        v.iter().map(|c| c.value).collect()
+    }
+
+    fn presorted<const N: usize>(indices: [usize; N]) -> PresortedIndices<N> {
+        PresortedIndices::new(indices).unwrap()
+    }
+
+    #[test]
+    fn test_remove_normal() {
+        let mut v = vec![1, 2, 3, 4, 5, 6, 7];
+        let removed = remove_many(&mut v, presorted([1, 2, 5]));
+        assert_eq!(removed, [2, 3, 6]);
+        assert_eq!(v, vec![1, 4, 5, 7]);
+    }
+
+    #[test]
+    fn test_remove_first_last() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let removed = remove_many(&mut v, presorted([0, 4]));
+        assert_eq!(removed, [1, 5]);
+        assert_eq!(v, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_remove_all() {
+        let mut v = vec![1, 2, 3];
+        let removed = remove_many(&mut v, presorted([0, 1, 2]));
+        assert_eq!(removed, [1, 2, 3]);
+        assert_eq!(v, vec![]);
+    }
+
+    #[test]
+    fn test_remove_empty() {
+        let mut v = vec![1, 2, 3];
+        let [] = remove_many(&mut v, presorted([]));
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 5 is out of bounds of slice with len 5 (indices [1, 5], position 1)"
+    )]
+    fn test_remove_oob() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        remove_many(&mut v, presorted([1, 5]));
+    }
+
+    #[test]
+    fn test_insert_normal() {
+        let mut v = vec![1, 4, 5, 7];
+        insert_many(&mut v, [(1, 2), (2, 3), (5, 6)]);
+        assert_eq!(v, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_insert_first_last() {
+        let mut v = vec![2, 3, 4];
+        insert_many(&mut v, [(0, 1), (4, 5)]);
+        assert_eq!(v, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_insert_into_empty() {
+        let mut v = vec![];
+        insert_many(&mut v, [(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_insert_empty() {
+        let mut v = vec![1, 2, 3];
+        insert_many::<i32, 0>(&mut v, []);
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Indices [2, 1] are not sorted")]
+    fn test_insert_unsorted() {
+        let mut v = vec![1, 2, 3];
+        insert_many(&mut v, [(2, 0), (1, 0)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 5 is out of bounds of slice with len 5 (indices [0, 5], position 1)"
+    )]
+    fn test_insert_oob() {
+        let mut v = vec![1, 2, 3];
+        insert_many(&mut v, [(0, 0), (5, 0)]);
+    }
+
+    #[test]
+    fn test_remove_insert_roundtrip() {
+        let mut v: Vec<i32> = (0..20).collect();
+        let indices = presorted([0, 3, 4, 11, 19]);
+        let removed = remove_many(&mut v, indices);
+        assert_eq!(removed, [0, 3, 4, 11, 19]);
+        let mut values = [(0, 0); 5];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = (indices[i], removed[i]);
+        }
+        insert_many(&mut v, values);
+        assert_eq!(v, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_dyn_normal() {
+        let mut v = vec![1, 2, 3, 4, 5, 6, 7];
+        let removed = remove_many_dyn(&mut v, &[1, 2, 5]);
+        assert_eq!(removed, vec![2, 3, 6]);
+        assert_eq!(v, vec![1, 4, 5, 7]);
+
+        insert_many_dyn(&mut v, vec![(1, 2), (2, 3), (5, 6)]);
+        assert_eq!(v, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 3 appears more than once (indices [1, 3, 3], position 1 and 2)"
+    )]
+    fn test_dyn_duplicate() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        remove_many_dyn(&mut v, &[1, 3, 3]);
+    }
+
+    #[test]
+    fn test_remove_drops() {
+        let drops = Rc::new(Cell::new(0));
+        let mut v = Vec::from(counters([1, 2, 3, 4, 5], &drops));
+
+        let removed = remove_many(&mut v, presorted([1, 3]));
+        assert_eq!(drops.get(), 0);
+        assert_eq!(values(&removed), vec![2, 4]);
+        assert_eq!(values(&v), vec![1, 3, 5]);
+
+        drop(removed);
+        assert_eq!(drops.get(), 2);
+        drop(v);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_insert_drops() {
+        let drops = Rc::new(Cell::new(0));
+        let mut v = Vec::from(counters([1, 3, 5], &drops));
+        let mut new = Vec::from(counters([2, 4], &drops)).into_iter();
+        let a = new.next().unwrap();
+        let b = new.next().unwrap();
+
+        insert_many(&mut v, [(1, a), (3, b)]);
+        assert_eq!(drops.get(), 0);
+        assert_eq!(values(&v), vec![1, 2, 3, 4, 5]);
+
+        drop(v);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_dyn_drops() {
+        let drops = Rc::new(Cell::new(0));
+        let mut v = Vec::from(counters([1, 2, 3, 4, 5], &drops));
+
+        let removed = remove_many_dyn(&mut v, &[0, 2, 4]);
+        assert_eq!(drops.get(), 0);
+        let positions = [0, 2, 4];
+        let values_back = positions.iter().copied().zip(removed).collect();
+        insert_many_dyn(&mut v, values_back);
+        assert_eq!(drops.get(), 0);
+        assert_eq!(values(&v), vec![1, 2, 3, 4, 5]);
+
+        drop(v);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_insert_invalid_drops_values_once() {
+        let drops = Rc::new(Cell::new(0));
+        let mut v = Vec::from(counters([1, 2, 3], &drops));
+        let mut new = Vec::from(counters([8, 9], &drops)).into_iter();
+        let a = new.next().unwrap();
+        let b = new.next().unwrap();
+
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            insert_many(&mut v, [(3, a), (1, b)]);
+        }));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 2);
+        assert_eq!(values(&v), vec![1, 2, 3]);
+
+        drop(v);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_take_drops() {
+        let drops = Rc::new(Cell::new(0));
+        let v = Vec::from(counters([1, 2, 3, 4, 5], &drops));
+
+        let [a, b] = take_many(v, UnsortedIndices([4, 0]));
+        assert_eq!(drops.get(), 3);
+        assert_eq!((a.value, b.value), (5, 1));
+
+        drop((a, b));
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn test_take_panicking_drop() {
+        struct PanicOnDrop(i32, Rc<Cell<usize>>);
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+                if self.0 == 1 {
+                    panic!("element 1 panics");
+                }
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let v: Vec<_> = (0..6).map(|i| PanicOnDrop(i, drops.clone())).collect();
+
+        let res = catch_unwind(AssertUnwindSafe(|| take_many(v, UnsortedIndices([4, 2]))));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 2 appears more than once (indices [2, 2], position 0 and 1)")]
+    fn test_take_duplicate() {
+        take_many(vec![1, 2, 3], UnsortedIndices([2, 2]));
+    }
+
+    #[test]
+    fn test_panicking_drop() {
+        struct PanicOnDrop(Rc<Cell<usize>>);
+
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+                if self.0.get() == 1 {
+                    panic!("first drop panics");
+                }
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut v: Vec<_> = (0..4).map(|_| PanicOnDrop(drops.clone())).collect();
+
+        let removed = remove_many(&mut v, presorted([0, 2]));
+        let res = catch_unwind(AssertUnwindSafe(|| drop(removed)));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 2);
+        assert_eq!(v.len(), 2);
+
+        drop(v);
+        assert_eq!(drops.get(), 4);
+    }
+}
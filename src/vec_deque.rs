@@ -0,0 +1,350 @@
+//! A variant of the API for `VecDeque`, using logical indices.
+//!
+//! A `VecDeque` stores its elements in a ring buffer, which appears as two
+//! slices from [`VecDeque::as_mut_slices()`]. The logical indices used here
+//! are mapped onto these two slices.
+//!
+//! Single elements are selected with any `I: Indices<N>`, like in
+//! [`crate::generic`]. This includes plain sorted arrays, like in
+//! [`crate::simple`].
+//!
+//! Ranges have to be sorted and must not overlap, like in
+//! [`crate::slice_index`]. A range can straddle the point where the ring
+//! buffer wraps around, so each range is returned as two parts, the second of
+//! which is empty unless the range straddles that point. Alternatively,
+//! [`get_many_ranges_contiguous_mut()`] rearranges the deque with
+//! [`VecDeque::make_contiguous()`] to return each range as a single slice.
+//!
+//! # Example
+//! ```
+//! use std::collections::VecDeque;
+//! use index_many::vec_deque::{index_many_mut, index_many_ranges_mut};
+//!
+//! let mut d = VecDeque::from(vec![3, 4, 5]);
+//! d.push_front(2);
+//! d.push_front(1);
+//!
+//! let [a, b] = index_many_mut(&mut d, [0, 4]);
+//! std::mem::swap(a, b);
+//! assert_eq!(d, [5, 2, 3, 4, 1]);
+//!
+//! let [(head, tail)] = index_many_ranges_mut(&mut d, [1..4]);
+//! assert_eq!(head.len() + tail.len(), 3);
+//! ```
+
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::ptr;
+
+use crate::generic::Indices;
+
+/// The two parts of a range of a `VecDeque`, in logical order.
+pub type RangeParts<'a, T> = (&'a [T], &'a [T]);
+
+/// The two parts of a mutable range of a `VecDeque`, in logical order.
+pub type RangePartsMut<'a, T> = (&'a mut [T], &'a mut [T]);
+
+/// Maps the in-bounds logical `idx` onto the two parts of a ring buffer.
+#[inline]
+unsafe fn element_ptr<T>(head: *mut [T], tail: *mut [T], idx: usize) -> *mut T {
+    if idx < head.len() {
+        (head as *mut T).add(idx)
+    } else {
+        (tail as *mut T).add(idx - head.len())
+    }
+}
+
+/// Maps the in-bounds logical `range` onto the two parts of a ring buffer.
+#[inline]
+unsafe fn range_ptrs<T>(
+    head: *mut [T],
+    tail: *mut [T],
+    range: Range<usize>,
+) -> (*mut [T], *mut [T]) {
+    let split = head.len();
+    let head_start = range.start.min(split);
+    let head_end = range.end.min(split);
+    let tail_start = range.start.saturating_sub(split);
+    let tail_end = range.end.saturating_sub(split);
+    (
+        ptr::slice_from_raw_parts_mut((head as *mut T).add(head_start), head_end - head_start),
+        ptr::slice_from_raw_parts_mut((tail as *mut T).add(tail_start), tail_end - tail_start),
+    )
+}
+
+/// # Safety
+/// The indices have to be valid for `deque`.
+pub unsafe fn index_many_unchecked<T, I: Indices<N>, const N: usize>(
+    deque: &VecDeque<T>,
+    indices: I,
+) -> [&T; N] {
+    let (head, tail) = deque.as_slices();
+    let (head, tail) = (
+        head as *const [T] as *mut [T],
+        tail as *const [T] as *mut [T],
+    );
+    indices
+        .to_raw_indices()
+        .map(|idx| &*element_ptr(head, tail, idx))
+}
+
+/// # Safety
+/// The indices have to be valid for `deque`.
+pub unsafe fn index_many_mut_unchecked<T, I: Indices<N>, const N: usize>(
+    deque: &mut VecDeque<T>,
+    indices: I,
+) -> [&mut T; N] {
+    let (head, tail) = deque.as_mut_slices();
+    let (head, tail) = (head as *mut [T], tail as *mut [T]);
+    indices
+        .to_raw_indices()
+        .map(|idx| &mut *element_ptr(head, tail, idx))
+}
+
+pub fn get_many<T, I: Indices<N>, const N: usize>(
+    deque: &VecDeque<T>,
+    indices: I,
+) -> Option<[&T; N]> {
+    if !indices.is_valid(deque.len()) {
+        return None;
+    }
+    unsafe { Some(index_many_unchecked(deque, indices)) }
+}
+
+pub fn get_many_mut<T, I: Indices<N>, const N: usize>(
+    deque: &mut VecDeque<T>,
+    indices: I,
+) -> Option<[&mut T; N]> {
+    if !indices.is_valid(deque.len()) {
+        return None;
+    }
+    unsafe { Some(index_many_mut_unchecked(deque, indices)) }
+}
+
+pub fn index_many<T, I: Indices<N>, const N: usize>(deque: &VecDeque<T>, indices: I) -> [&T; N] {
+    match get_many(deque, indices) {
+        Some(s) => s,
+        None => indices.cause_invalid_panic(deque.len()),
+    }
+}
+
+pub fn index_many_mut<T, I: Indices<N>, const N: usize>(
+    deque: &mut VecDeque<T>,
+    indices: I,
+) -> [&mut T; N] {
+    let len = deque.len();
+    match get_many_mut(deque, indices) {
+        Some(s) => s,
+        None => indices.cause_invalid_panic(len),
+    }
+}
+
+/// # Safety
+/// The ranges have to be in bounds of `deque`, sorted, and must not overlap.
+pub unsafe fn index_many_ranges_unchecked<T, const N: usize>(
+    deque: &VecDeque<T>,
+    ranges: [Range<usize>; N],
+) -> [RangeParts<'_, T>; N] {
+    let (head, tail) = deque.as_slices();
+    let (head, tail) = (
+        head as *const [T] as *mut [T],
+        tail as *const [T] as *mut [T],
+    );
+    ranges.map(|range| {
+        let (a, b) = range_ptrs(head, tail, range);
+        (&*a, &*b)
+    })
+}
+
+/// # Safety
+/// The ranges have to be in bounds of `deque`, sorted, and must not overlap.
+pub unsafe fn index_many_ranges_mut_unchecked<T, const N: usize>(
+    deque: &mut VecDeque<T>,
+    ranges: [Range<usize>; N],
+) -> [RangePartsMut<'_, T>; N] {
+    let (head, tail) = deque.as_mut_slices();
+    let (head, tail) = (head as *mut [T], tail as *mut [T]);
+    ranges.map(|range| {
+        let (a, b) = range_ptrs(head, tail, range);
+        (&mut *a, &mut *b)
+    })
+}
+
+pub fn get_many_ranges<T, const N: usize>(
+    deque: &VecDeque<T>,
+    ranges: [Range<usize>; N],
+) -> Option<[RangeParts<'_, T>; N]> {
+    if !crate::slice_index::check_range_indices_valid(&ranges, deque.len()) {
+        return None;
+    }
+    unsafe { Some(index_many_ranges_unchecked(deque, ranges)) }
+}
+
+pub fn get_many_ranges_mut<T, const N: usize>(
+    deque: &mut VecDeque<T>,
+    ranges: [Range<usize>; N],
+) -> Option<[RangePartsMut<'_, T>; N]> {
+    if !crate::slice_index::check_range_indices_valid(&ranges, deque.len()) {
+        return None;
+    }
+    unsafe { Some(index_many_ranges_mut_unchecked(deque, ranges)) }
+}
+
+pub fn index_many_ranges<T, const N: usize>(
+    deque: &VecDeque<T>,
+    ranges: [Range<usize>; N],
+) -> [RangeParts<'_, T>; N] {
+    if !crate::slice_index::check_range_indices_valid(&ranges, deque.len()) {
+        crate::slice_index::range_check_fail(&ranges, deque.len());
+    }
+    unsafe { index_many_ranges_unchecked(deque, ranges) }
+}
+
+pub fn index_many_ranges_mut<T, const N: usize>(
+    deque: &mut VecDeque<T>,
+    ranges: [Range<usize>; N],
+) -> [RangePartsMut<'_, T>; N] {
+    if !crate::slice_index::check_range_indices_valid(&ranges, deque.len()) {
+        crate::slice_index::range_check_fail(&ranges, deque.len());
+    }
+    unsafe { index_many_ranges_mut_unchecked(deque, ranges) }
+}
+
+/// Makes the deque contiguous with [`VecDeque::make_contiguous()`], and
+/// returns the ranges as single slices, if they are valid for `deque`.
+///
+/// The deque is only rearranged if the ranges are valid.
+pub fn get_many_ranges_contiguous_mut<T, const N: usize>(
+    deque: &mut VecDeque<T>,
+    ranges: [Range<usize>; N],
+) -> Option<[&mut [T]; N]> {
+    if !crate::slice_index::check_range_indices_valid(&ranges, deque.len()) {
+        return None;
+    }
+    let slice: *mut [T] = deque.make_contiguous();
+    // SAFETY: The ranges are valid for the contiguous slice.
+    unsafe { Some(crate::get_many_internal_mut(slice, ranges)) }
+}
+
+/// Makes the deque contiguous with [`VecDeque::make_contiguous()`], and
+/// returns the ranges as single slices.
+///
+/// # Panics
+/// Panics if the ranges are not valid for `deque`.
+pub fn index_many_ranges_contiguous_mut<T, const N: usize>(
+    deque: &mut VecDeque<T>,
+    ranges: [Range<usize>; N],
+) -> [&mut [T]; N] {
+    let len = deque.len();
+    if !crate::slice_index::check_range_indices_valid(&ranges, len) {
+        crate::slice_index::range_check_fail(&ranges, len);
+    }
+    crate::slice_index::index_many_mut(deque.make_contiguous(), ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generic::UnsortedIndices;
+
+    /// Returns the deque `0..len`, which wraps around after `wrap` elements.
+    fn wrapped(len: usize, wrap: usize) -> VecDeque<usize> {
+        let mut d = VecDeque::with_capacity(len);
+        for i in wrap..len {
+            d.push_back(i);
+        }
+        for i in (0..wrap).rev() {
+            d.push_front(i);
+        }
+        d
+    }
+
+    #[test]
+    fn test_mut_normal() {
+        let mut d = wrapped(8, 3);
+        assert!(!d.as_slices().1.is_empty());
+        let [a, b, c] = index_many_mut(&mut d, [0, 3, 7]);
+        assert_eq!((*a, *b, *c), (0, 3, 7));
+        *a += 10;
+        *b += 100;
+        *c += 1000;
+        assert_eq!(d, [10, 1, 2, 103, 4, 5, 6, 1007]);
+    }
+
+    #[test]
+    fn test_ref_unsorted() {
+        for wrap in 0..8 {
+            let d = wrapped(8, wrap);
+            let [a, b, c] = index_many(&d, UnsortedIndices([6, 1, 4]));
+            assert_eq!((*a, *b, *c), (6, 1, 4));
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut d = wrapped(5, 2);
+        assert!(get_many_mut(&mut d, [1, 5]).is_none());
+        assert!(get_many_mut(&mut d, [3, 1]).is_none());
+        assert!(get_many(&d, UnsortedIndices([2, 2])).is_none());
+        assert!(get_many(&d, UnsortedIndices([4, 0])).is_some());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Index 5 is out of bounds of slice with len 5 (indices [1, 5], position 1)"
+    )]
+    fn test_oob() {
+        let mut d = wrapped(5, 2);
+        index_many_mut(&mut d, [1, 5]);
+    }
+
+    #[test]
+    fn test_ranges() {
+        for wrap in 0..8 {
+            let mut d = wrapped(8, wrap);
+            let [(a1, a2), (b1, b2)] = index_many_ranges_mut(&mut d, [1..4, 5..8]);
+            let a: Vec<_> = a1.iter().chain(a2.iter()).copied().collect();
+            let b: Vec<_> = b1.iter().chain(b2.iter()).copied().collect();
+            assert_eq!((a, b), (vec![1, 2, 3], vec![5, 6, 7]));
+            for x in a1.iter_mut().chain(a2.iter_mut()) {
+                *x *= 10;
+            }
+            assert_eq!(d, [0, 10, 20, 30, 4, 5, 6, 7]);
+        }
+    }
+
+    #[test]
+    fn test_range_straddling() {
+        let d = wrapped(8, 3);
+        let [(a, b), (c, e)] = index_many_ranges(&d, [0..2, 2..5]);
+        assert_eq!((a, b), (&[0, 1][..], &[][..]));
+        assert_eq!((c, e), (&[2][..], &[3, 4][..]));
+    }
+
+    #[test]
+    fn test_ranges_invalid() {
+        let mut d = wrapped(5, 2);
+        assert!(get_many_ranges_mut(&mut d, [0..2, 1..3]).is_none());
+        assert!(get_many_ranges(&d, [3..4, 0..1]).is_none());
+        assert!(get_many_ranges(&d, [0..2, 4..6]).is_none());
+        assert!(get_many_ranges_contiguous_mut(&mut d, [0..2, 4..6]).is_none());
+        assert!(!d.as_slices().1.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Range 4..6 is out of bounds of slice with len 5")]
+    fn test_ranges_oob() {
+        let mut d = wrapped(5, 2);
+        index_many_ranges_mut(&mut d, [0..2, 4..6]);
+    }
+
+    #[test]
+    fn test_ranges_contiguous() {
+        let mut d = wrapped(8, 3);
+        let [a, b] = index_many_ranges_contiguous_mut(&mut d, [1..5, 6..8]);
+        assert_eq!((&*a, &*b), (&[1, 2, 3, 4][..], &[6, 7][..]));
+        a[..2].swap_with_slice(b);
+        assert!(d.as_slices().1.is_empty());
+        assert_eq!(d, [0, 6, 7, 3, 4, 5, 1, 2]);
+    }
+}
@@ -0,0 +1,162 @@
+//! Functions for visiting overlapping mutable windows of a slice.
+//!
+//! Overlapping mutable windows can not be returned from an iterator, since
+//! they would alias once two of them are alive at the same time. Instead, each
+//! window is handed to a closure, and can not outlive that call.
+//!
+//! The bounds of all windows are checked once up front, so each individual
+//! window costs no further checks.
+//!
+//! # Example
+//! ```
+//! use index_many::windows::for_each_window_mut;
+//!
+//! // Prefix sum
+//! let mut v = vec![1, 2, 3, 4, 5];
+//! for_each_window_mut(&mut v, |[a, b]| *b += *a);
+//! assert_eq!(v, vec![1, 3, 6, 10, 15]);
+//! ```
+
+use crate::generic::{Indices, Strided};
+
+/// Calls `f` with each window of `N` consecutive elements, from front to back.
+///
+/// Does nothing if the slice is shorter than `N`.
+///
+/// # Panics
+/// Panics if `N == 0`.
+pub fn for_each_window_mut<T, F, const N: usize>(slice: &mut [T], f: F)
+where
+    F: FnMut([&mut T; N]),
+{
+    for_each_window_mut_strided(slice, 1, f)
+}
+
+/// Calls `f` with each window of `N` consecutive elements, whose first
+/// element is a multiple of `step` elements from the front of the slice.
+///
+/// Does nothing if the slice is shorter than `N`.
+///
+/// # Panics
+/// Panics if `N == 0` or `step == 0`.
+///
+/// # Example
+/// ```
+/// use index_many::windows::for_each_window_mut_strided;
+///
+/// let mut v = vec![1, 2, 3, 4, 5, 6, 7];
+/// for_each_window_mut_strided(&mut v, 2, |[a, _, c]| *c += *a);
+/// assert_eq!(v, vec![1, 2, 4, 4, 9, 6, 16]);
+/// ```
+pub fn for_each_window_mut_strided<T, F, const N: usize>(slice: &mut [T], step: usize, mut f: F)
+where
+    F: FnMut([&mut T; N]),
+{
+    assert!(N != 0, "window size must be non-zero");
+    assert!(step != 0, "step must be non-zero");
+
+    let last_start = match slice.len().checked_sub(N) {
+        Some(last_start) => last_start,
+        None => return,
+    };
+
+    let slice: *mut [T] = slice;
+    let mut start = 0;
+    loop {
+        let indices = Strided::<N> { start, step: 1 }.to_raw_indices();
+        // SAFETY: The indices are unique, and in bounds because
+        // `start <= last_start`. The references handed to `f` can not
+        // outlive the call, so they never alias those of the next window.
+        unsafe { f(crate::get_many_internal_mut(slice, indices)) };
+
+        start = match start.checked_add(step) {
+            Some(start) if start <= last_start => start,
+            _ => break,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows() {
+        let mut v = vec![1, 2, 3, 4, 5];
+        let mut seen = vec![];
+        for_each_window_mut(&mut v, |[a, b, c]| {
+            seen.push([*a, *b, *c]);
+            *b += 10;
+        });
+        assert_eq!(seen, vec![[1, 2, 3], [12, 3, 4], [13, 4, 5]]);
+        assert_eq!(v, vec![1, 12, 13, 14, 5]);
+    }
+
+    #[test]
+    fn test_windows_full_and_short() {
+        let mut v = vec![1, 2, 3];
+        let mut calls = 0;
+        for_each_window_mut(&mut v, |[a, _, c]| {
+            std::mem::swap(a, c);
+            calls += 1;
+        });
+        assert_eq!(calls, 1);
+        assert_eq!(v, vec![3, 2, 1]);
+
+        for_each_window_mut(&mut v, |[_, _, _, _]: [&mut i32; 4]| unreachable!());
+        for_each_window_mut(&mut [], |[_]: [&mut i32; 1]| unreachable!());
+    }
+
+    #[test]
+    fn test_windows_single() {
+        let mut v = vec![1, 2, 3];
+        for_each_window_mut(&mut v, |[a]| *a *= 2);
+        assert_eq!(v, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_windows_interleaved_writes() {
+        let mut v = vec![0; 6];
+        for_each_window_mut(&mut v, |[a, b, c]| {
+            *a += 1;
+            *c += *a;
+            *b += *c;
+            *a += *b;
+        });
+        assert_eq!(v, vec![2, 5, 10, 18, 11, 7]);
+    }
+
+    #[test]
+    fn test_strided() {
+        let mut v: Vec<i32> = (0..10).collect();
+        let mut starts = vec![];
+        for_each_window_mut_strided(&mut v, 3, |[a, b]| {
+            starts.push(*a);
+            std::mem::swap(a, b);
+        });
+        assert_eq!(starts, vec![0, 3, 6]);
+        assert_eq!(v, vec![1, 0, 2, 4, 3, 5, 7, 6, 8, 9]);
+    }
+
+    #[test]
+    fn test_strided_large_step() {
+        let mut v = vec![1, 2, 3];
+        let mut calls = 0;
+        for_each_window_mut_strided(&mut v, usize::MAX, |[_, _]| calls += 1);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be non-zero")]
+    fn test_strided_zero() {
+        let mut v = vec![1, 2, 3];
+        for_each_window_mut_strided(&mut v, 0, |[_, _]| {});
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_empty_window() {
+        let mut v = vec![1, 2, 3];
+        for_each_window_mut(&mut v, |[]: [&mut i32; 0]| {});
+    }
+}